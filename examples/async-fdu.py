@@ -0,0 +1,42 @@
+###############################################################################
+# Copyright (c) 2018,2021 ADLINK Technology Inc.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Eclipse Public License 2.0 which is available at
+# http://www.eclipse.org/legal/epl-2.0, or the Apache Software License 2.0
+# which is available at https://www.apache.org/licenses/LICENSE-2.0.
+#
+# SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+# Contributors:
+#   ADLINK fog05 team, <fog05@adlink-labs.tech>
+###############################################################################
+
+import asyncio
+from fog05 import FosZenohSession
+from fog05.api import AsyncFduApi
+from fog05.im.fdu import FduDescriptor
+
+
+## Onboards, defines and starts the FDU in fdu-native.json
+## without blocking the asyncio event loop
+
+async def main():
+
+    locator = 'tcp/127.0.0.1:61189'
+    zenoh_session = FosZenohSession(locator)
+    api = AsyncFduApi(zenoh_session)
+
+    with open('fdu-native.json', 'rb') as f:
+        descriptor = FduDescriptor.deserialize_json(f.read())
+
+    descriptor.computation_requirements.ram_size_mb = 64
+
+    fdu_uuid = await api.onboard_fdu(descriptor)
+    print(f'Onboarded {fdu_uuid}')
+    record = await api.define_fdu(fdu_uuid, timeout=30)
+    print(f'Defined {record}')
+    print('Bye')
+
+
+if __name__=='__main__':
+    asyncio.get_event_loop().run_until_complete(main())
@@ -17,6 +17,7 @@ from fog05.plugins import WrappedHypervisor, AgentClient
 import sys
 from pprint import pprint
 import time
+import uuid
 
 
 ## This example is the Python version of:
@@ -28,12 +29,16 @@ def press_to_continue():
 
 
 class DummyHypervisor(object):
-    def __init__(self, agent):
+    def __init__(self, agent, node_uuid):
         self.name = "DummyPython"
         self.agent = agent
+        self.node_uuid = node_uuid
+        self.instances = {}
 
     def define_fdu(self, descriptor):
-        return FduRecord()
+        record = FduRecord(fdu_uuid=descriptor.uuid or str(uuid.uuid4()), node=self.node_uuid, status="DEFINED")
+        self.instances[record.uuid] = record
+        return record
 
     def undefine_fdu(self, instance_uuid):
         return instance_uuid
@@ -51,7 +56,7 @@ class DummyHypervisor(object):
         return instance_uuid
 
     def get_fdu_status(self, instance_uuid):
-         return FduRecord()
+        return self.instances[instance_uuid]
 
     def run_fdu(self, instance_uuid):
         return instance_uuid
@@ -76,7 +81,7 @@ def main():
 
         agent = AgentClient(zenoh_session, local_servers[0])
 
-        py_hypervisor = DummyHypervisor(agent)
+        py_hypervisor = DummyHypervisor(agent, agent.get_node_uuid())
 
         hypervisor = WrappedHypervisor(py_hypervisor, zenoh_session)
         hypervisor.start()
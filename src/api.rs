@@ -11,135 +11,312 @@
 *   ADLINK fog05 team, <fog05@adlink-labs.tech>
 *********************************************************************************/
 
-use async_std::sync::Arc;
-use async_std::task;
 use fog05_sdk::api;
 use pyo3::prelude::*;
 use pyo3::PyObjectProtocol;
-use uuid::Uuid;
 
-use crate::to_pyerr;
 use crate::FosZenohSession;
+use crate::{parse_uuid, to_pyerr};
 
 #[pyclass]
 #[derive(Clone)]
 pub struct FduApi {
-    pub a: Arc<api::FDUApi>,
+    pub z: FosZenohSession,
+}
+
+/// Same as `FduApi` but every method returns an asyncio awaitable.
+///
+/// The awaitables are bound to the event loop running at the first async
+/// call of the process, and that loop must stay the only one:
+/// awaiting them from a loop created later, e.g. by a second `asyncio.run()`,
+/// is not supported.
+#[pyclass]
+#[derive(Clone)]
+pub struct AsyncFduApi {
+    pub z: FosZenohSession,
 }
 
 #[pymodule]
 pub fn api(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<FduApi>()?;
+    m.add_class::<AsyncFduApi>()?;
 
     Ok(())
 }
 
-// Cannot use async until: https://github.com/PyO3/pyo3/pull/1406
-
 #[pymethods]
 impl FduApi {
     #[new]
     fn new(zenoh: FosZenohSession) -> PyResult<Self> {
-        task::block_on(async {
-            let a = Arc::new(api::FDUApi::new(
-                zenoh.zconnector.clone(),
-                zenoh.zsession.clone(),
-            ));
-            Ok(Self { a })
-        })
+        zenoh.handles()?;
+        Ok(Self { z: zenoh })
     }
 
-    fn onboard_fdu(&self, fdu: crate::im::fdu::FduDescriptor) -> PyResult<String> {
-        task::block_on(async {
-            let fdu_uuid = self.a.onboard_fdu(fdu.d).await.map_err(to_pyerr)?;
+    #[args(timeout = "None")]
+    fn onboard_fdu(
+        &self,
+        fdu: crate::im::fdu::FduDescriptor,
+        timeout: Option<f64>,
+    ) -> PyResult<String> {
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let fdu_uuid = a.onboard_fdu(fdu.d).await.map_err(to_pyerr)?;
             Ok(format!("{}", fdu_uuid))
         })
     }
 
+    #[args(timeout = "None")]
     fn define_fdu(
         &self,
         fdu_uuid: String,
         node_uuid: Option<String>,
+        timeout: Option<f64>,
     ) -> PyResult<crate::im::fdu::FduRecord> {
-        let fdu_uuid = Uuid::parse_str(&fdu_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
         let node_uuid = match node_uuid {
-            Some(id) => Some(
-                Uuid::parse_str(&id)
-                    .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?,
-            ),
+            Some(id) => Some(parse_uuid(&id)?),
             None => None,
         };
 
-        task::block_on(async {
-            let r = self
-                .a
-                .define_fdu(fdu_uuid, node_uuid)
-                .await
-                .map_err(to_pyerr)?;
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let r = a.define_fdu(fdu_uuid, node_uuid).await.map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduRecord { r })
         })
     }
 
-    fn configure_fdu(&self, instance_uuid: String) -> PyResult<crate::im::fdu::FduRecord> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let r = self
-                .a
-                .configure_fdu(instance_uuid)
-                .await
-                .map_err(to_pyerr)?;
+    #[args(timeout = "None")]
+    fn configure_fdu(
+        &self,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<crate::im::fdu::FduRecord> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let r = a.configure_fdu(instance_uuid).await.map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduRecord { r })
         })
     }
 
-    fn start_fdu(&self, instance_uuid: String) -> PyResult<crate::im::fdu::FduRecord> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let r = self.a.start_fdu(instance_uuid).await.map_err(to_pyerr)?;
+    #[args(timeout = "None")]
+    fn start_fdu(
+        &self,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<crate::im::fdu::FduRecord> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let r = a.start_fdu(instance_uuid).await.map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduRecord { r })
         })
     }
 
-    fn stop_fdu(&self, instance_uuid: String) -> PyResult<crate::im::fdu::FduRecord> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let r = self.a.stop_fdu(instance_uuid).await.map_err(to_pyerr)?;
+    #[args(timeout = "None")]
+    fn stop_fdu(
+        &self,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<crate::im::fdu::FduRecord> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let r = a.stop_fdu(instance_uuid).await.map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduRecord { r })
         })
     }
 
-    fn clean_fdu(&self, instance_uuid: String) -> PyResult<crate::im::fdu::FduRecord> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let r = self.a.clean_fdu(instance_uuid).await.map_err(to_pyerr)?;
+    #[args(timeout = "None")]
+    fn clean_fdu(
+        &self,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<crate::im::fdu::FduRecord> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let r = a.clean_fdu(instance_uuid).await.map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduRecord { r })
         })
     }
 
-    fn undefine_fdu(&self, instance_uuid: String) -> PyResult<crate::im::fdu::FduRecord> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let r = self.a.undefine_fdu(instance_uuid).await.map_err(to_pyerr)?;
+    #[args(timeout = "None")]
+    fn undefine_fdu(
+        &self,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<crate::im::fdu::FduRecord> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let r = a.undefine_fdu(instance_uuid).await.map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduRecord { r })
         })
     }
 
-    fn offload_fdu(&self, fdu_uuid: String) -> PyResult<String> {
-        let fdu_uuid = Uuid::parse_str(&fdu_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let r = self.a.offload_fdu(fdu_uuid).await.map_err(to_pyerr)?;
+    #[args(timeout = "None")]
+    fn offload_fdu(&self, fdu_uuid: String, timeout: Option<f64>) -> PyResult<String> {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        let a = self.api()?;
+        self.z.call(timeout, async {
+            let r = a.offload_fdu(fdu_uuid).await.map_err(to_pyerr)?;
             Ok(format!("{}", r))
         })
     }
 }
 
+impl FduApi {
+    fn api(&self) -> PyResult<api::FDUApi> {
+        fdu_api(&self.z)
+    }
+}
+
+#[pymethods]
+impl AsyncFduApi {
+    #[new]
+    fn new(zenoh: FosZenohSession) -> PyResult<Self> {
+        zenoh.handles()?;
+        Ok(Self { z: zenoh })
+    }
+
+    #[args(timeout = "None")]
+    fn onboard_fdu(
+        &self,
+        py: Python,
+        fdu: crate::im::fdu::FduDescriptor,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let fdu_uuid = a.onboard_fdu(fdu.d).await.map_err(to_pyerr)?;
+            Ok(format!("{}", fdu_uuid))
+        })
+    }
+
+    #[args(timeout = "None")]
+    fn define_fdu(
+        &self,
+        py: Python,
+        fdu_uuid: String,
+        node_uuid: Option<String>,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        let node_uuid = match node_uuid {
+            Some(id) => Some(parse_uuid(&id)?),
+            None => None,
+        };
+
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let r = a.define_fdu(fdu_uuid, node_uuid).await.map_err(to_pyerr)?;
+            Ok(crate::im::fdu::FduRecord { r })
+        })
+    }
+
+    #[args(timeout = "None")]
+    fn configure_fdu(
+        &self,
+        py: Python,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let r = a.configure_fdu(instance_uuid).await.map_err(to_pyerr)?;
+            Ok(crate::im::fdu::FduRecord { r })
+        })
+    }
+
+    #[args(timeout = "None")]
+    fn start_fdu(
+        &self,
+        py: Python,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let r = a.start_fdu(instance_uuid).await.map_err(to_pyerr)?;
+            Ok(crate::im::fdu::FduRecord { r })
+        })
+    }
+
+    #[args(timeout = "None")]
+    fn stop_fdu(
+        &self,
+        py: Python,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let r = a.stop_fdu(instance_uuid).await.map_err(to_pyerr)?;
+            Ok(crate::im::fdu::FduRecord { r })
+        })
+    }
+
+    #[args(timeout = "None")]
+    fn clean_fdu(
+        &self,
+        py: Python,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let r = a.clean_fdu(instance_uuid).await.map_err(to_pyerr)?;
+            Ok(crate::im::fdu::FduRecord { r })
+        })
+    }
+
+    #[args(timeout = "None")]
+    fn undefine_fdu(
+        &self,
+        py: Python,
+        instance_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let r = a.undefine_fdu(instance_uuid).await.map_err(to_pyerr)?;
+            Ok(crate::im::fdu::FduRecord { r })
+        })
+    }
+
+    #[args(timeout = "None")]
+    fn offload_fdu(
+        &self,
+        py: Python,
+        fdu_uuid: String,
+        timeout: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        let a = self.api()?;
+        self.z.call_async(py, timeout, async move {
+            let r = a.offload_fdu(fdu_uuid).await.map_err(to_pyerr)?;
+            Ok(format!("{}", r))
+        })
+    }
+}
+
+impl AsyncFduApi {
+    fn api(&self) -> PyResult<api::FDUApi> {
+        fdu_api(&self.z)
+    }
+}
+
+pub(crate) fn fdu_api(z: &FosZenohSession) -> PyResult<api::FDUApi> {
+    let handles = z.handles()?;
+    Ok(api::FDUApi::new(handles.zconnector, handles.zsession))
+}
+
 #[pyproto]
 impl PyObjectProtocol for FduApi {
     fn __str__(&self) -> PyResult<String> {
@@ -154,3 +331,18 @@ impl PyObjectProtocol for FduApi {
         self.__str__()
     }
 }
+
+#[pyproto]
+impl PyObjectProtocol for AsyncFduApi {
+    fn __str__(&self) -> PyResult<String> {
+        Ok("AsyncFduApi".to_string())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        self.__str__()
+    }
+
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        self.__str__()
+    }
+}
@@ -0,0 +1,170 @@
+/*********************************************************************************
+* Copyright (c) 2018,2021 ADLINK Technology Inc.
+*
+* This program and the accompanying materials are made available under the
+* terms of the Eclipse Public License 2.0 which is available at
+* http://www.eclipse.org/legal/epl-2.0, or the Apache Software License 2.0
+* which is available at https://www.apache.org/licenses/LICENSE-2.0.
+*
+* SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+* Contributors:
+*   ADLINK fog05 team, <fog05@adlink-labs.tech>
+*********************************************************************************/
+
+use async_std::sync::Arc;
+use fog05_sdk::api;
+use fog05_sdk::im::fdu;
+use pyo3::prelude::*;
+use pyo3::PyObjectProtocol;
+use uuid::Uuid;
+
+use crate::api::fdu_api;
+use crate::FosZenohSession;
+use crate::{block_on_without_gil, parse_uuid, to_pyerr, with_timeout};
+
+/// Collects FDU API calls to run them concurrently with `execute`.
+#[pyclass]
+pub struct Batch {
+    pub z: FosZenohSession,
+    ops: Vec<Op>,
+}
+
+enum Op {
+    Onboard(fdu::FDUDescriptor),
+    Define(Uuid, Option<Uuid>),
+    Configure(Uuid),
+    Start(Uuid),
+    Stop(Uuid),
+    Clean(Uuid),
+    Undefine(Uuid),
+    Offload(Uuid),
+}
+
+#[pyfunction]
+pub fn batch(zenoh: FosZenohSession) -> PyResult<Batch> {
+    zenoh.handles()?;
+    Ok(Batch {
+        z: zenoh,
+        ops: vec![],
+    })
+}
+
+#[pymethods]
+impl Batch {
+    fn onboard_fdu(&mut self, fdu: crate::im::fdu::FduDescriptor) -> usize {
+        self.push(Op::Onboard(fdu.d))
+    }
+
+    fn define_fdu(&mut self, fdu_uuid: String, node_uuid: Option<String>) -> PyResult<usize> {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        let node_uuid = match node_uuid {
+            Some(id) => Some(parse_uuid(&id)?),
+            None => None,
+        };
+        Ok(self.push(Op::Define(fdu_uuid, node_uuid)))
+    }
+
+    fn configure_fdu(&mut self, instance_uuid: String) -> PyResult<usize> {
+        Ok(self.push(Op::Configure(parse_uuid(&instance_uuid)?)))
+    }
+
+    fn start_fdu(&mut self, instance_uuid: String) -> PyResult<usize> {
+        Ok(self.push(Op::Start(parse_uuid(&instance_uuid)?)))
+    }
+
+    fn stop_fdu(&mut self, instance_uuid: String) -> PyResult<usize> {
+        Ok(self.push(Op::Stop(parse_uuid(&instance_uuid)?)))
+    }
+
+    fn clean_fdu(&mut self, instance_uuid: String) -> PyResult<usize> {
+        Ok(self.push(Op::Clean(parse_uuid(&instance_uuid)?)))
+    }
+
+    fn undefine_fdu(&mut self, instance_uuid: String) -> PyResult<usize> {
+        Ok(self.push(Op::Undefine(parse_uuid(&instance_uuid)?)))
+    }
+
+    fn offload_fdu(&mut self, fdu_uuid: String) -> PyResult<usize> {
+        Ok(self.push(Op::Offload(parse_uuid(&fdu_uuid)?)))
+    }
+
+    /// Runs all the collected calls concurrently, each one bounded by
+    /// `timeout` or the session `request_timeout`.
+    ///
+    /// Returns one entry per call in submission order: the call result, or
+    /// the exception it raised. The batch is empty afterwards.
+    #[args(timeout = "None")]
+    fn execute(&mut self, timeout: Option<f64>) -> PyResult<Vec<PyObject>> {
+        let timeout = self.z.timeout_for(timeout)?;
+        let a = Arc::new(fdu_api(&self.z)?);
+        let calls: Vec<_> = std::mem::take(&mut self.ops)
+            .into_iter()
+            .map(|op| {
+                let a = a.clone();
+                async move { with_timeout(timeout, op.run(&a)).await }
+            })
+            .collect();
+        let results = block_on_without_gil(async { Ok(futures::future::join_all(calls).await) })?;
+        Ok(Python::with_gil(|py| {
+            results
+                .into_iter()
+                .map(|res| match res {
+                    Ok(obj) => obj,
+                    Err(err) => err.into_py(py),
+                })
+                .collect()
+        }))
+    }
+
+    #[getter]
+    fn pending(&self) -> usize {
+        self.ops.len()
+    }
+}
+
+impl Batch {
+    fn push(&mut self, op: Op) -> usize {
+        self.ops.push(op);
+        self.ops.len() - 1
+    }
+}
+
+impl Op {
+    async fn run(self, a: &api::FDUApi) -> PyResult<PyObject> {
+        let record = match self {
+            Op::Onboard(d) => {
+                let fdu_uuid = a.onboard_fdu(d).await.map_err(to_pyerr)?;
+                return Ok(Python::with_gil(|py| format!("{}", fdu_uuid).into_py(py)));
+            }
+            Op::Offload(fdu_uuid) => {
+                let r = a.offload_fdu(fdu_uuid).await.map_err(to_pyerr)?;
+                return Ok(Python::with_gil(|py| format!("{}", r).into_py(py)));
+            }
+            Op::Define(fdu_uuid, node_uuid) => a.define_fdu(fdu_uuid, node_uuid).await,
+            Op::Configure(instance_uuid) => a.configure_fdu(instance_uuid).await,
+            Op::Start(instance_uuid) => a.start_fdu(instance_uuid).await,
+            Op::Stop(instance_uuid) => a.stop_fdu(instance_uuid).await,
+            Op::Clean(instance_uuid) => a.clean_fdu(instance_uuid).await,
+            Op::Undefine(instance_uuid) => a.undefine_fdu(instance_uuid).await,
+        };
+        let r = record.map_err(to_pyerr)?;
+        Ok(Python::with_gil(|py| {
+            crate::im::fdu::FduRecord { r }.into_py(py)
+        }))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for Batch {
+    fn __str__(&self) -> PyResult<String> {
+        Ok(format!("Batch({} pending)", self.ops.len()))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        self.__str__()
+    }
+
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        self.__str__()
+    }
+}
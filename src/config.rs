@@ -0,0 +1,339 @@
+/*********************************************************************************
+* Copyright (c) 2018,2021 ADLINK Technology Inc.
+*
+* This program and the accompanying materials are made available under the
+* terms of the Eclipse Public License 2.0 which is available at
+* http://www.eclipse.org/legal/epl-2.0, or the Apache Software License 2.0
+* which is available at https://www.apache.org/licenses/LICENSE-2.0.
+*
+* SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+* Contributors:
+*   ADLINK fog05 team, <fog05@adlink-labs.tech>
+*********************************************************************************/
+
+use pyo3::exceptions;
+use pyo3::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use uuid::Uuid;
+
+use crate::{to_duration, SessionConfig};
+
+/// Session configuration as found in a YAML or JSON file, e.g.:
+///
+/// ```yaml
+/// locator: tcp/127.0.0.1:7447
+/// mode: client
+/// request_timeout: 10.0
+/// user: fos
+/// password: secret
+/// system_id: 00000000-0000-0000-0000-000000000000
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    locator: Option<String>,
+    mode: Option<String>,
+    peers: Vec<String>,
+    listeners: Vec<String>,
+    properties: HashMap<String, String>,
+    reconnect: Option<bool>,
+    request_timeout: Option<f64>,
+    user: Option<String>,
+    password: Option<String>,
+    tls_root_ca_certificate: Option<String>,
+    tls_client_certificate: Option<String>,
+    tls_client_private_key: Option<String>,
+    system_id: Option<Uuid>,
+    tenant_id: Option<Uuid>,
+}
+
+impl ConfigFile {
+    fn into_config(self) -> PyResult<SessionConfig> {
+        let mut peers: Vec<String> = self.locator.into_iter().collect();
+        peers.extend(self.peers);
+        Ok(SessionConfig {
+            mode: self.mode,
+            peers,
+            listeners: self.listeners,
+            properties: self.properties,
+            reconnect: self.reconnect.unwrap_or(true),
+            request_timeout: self.request_timeout.map(to_duration).transpose()?,
+            user: self.user,
+            password: self.password,
+            tls_root_ca_certificate: self.tls_root_ca_certificate,
+            tls_client_certificate: self.tls_client_certificate,
+            tls_client_private_key: self.tls_client_private_key,
+            system_id: self.system_id,
+            tenant_id: self.tenant_id,
+        })
+    }
+}
+
+/// Reads the configuration from `path`, JSON if the extension is `.json`,
+/// YAML otherwise.
+pub fn from_file(path: &str) -> PyResult<SessionConfig> {
+    let content = std::fs::read_to_string(path)?;
+    let is_json = Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("json"))
+        .unwrap_or(false);
+    let file: ConfigFile = if is_json {
+        parse_json(&content)?
+    } else {
+        parse_yaml(&content)?
+    };
+    file.into_config()
+}
+
+#[cfg(feature = "json")]
+fn parse_json(content: &str) -> PyResult<ConfigFile> {
+    crate::from_json(content.as_bytes())
+}
+
+#[cfg(not(feature = "json"))]
+fn parse_json(_content: &str) -> PyResult<ConfigFile> {
+    Err(PyErr::new::<exceptions::PyValueError, _>(
+        "JSON configuration files need the json feature",
+    ))
+}
+
+#[cfg(feature = "yaml")]
+fn parse_yaml(content: &str) -> PyResult<ConfigFile> {
+    crate::from_yaml(content.as_bytes())
+}
+
+#[cfg(not(feature = "yaml"))]
+fn parse_yaml(_content: &str) -> PyResult<ConfigFile> {
+    Err(PyErr::new::<exceptions::PyValueError, _>(
+        "YAML configuration files need the yaml feature",
+    ))
+}
+
+/// Builds the configuration from the `FOS_*` environment variables,
+/// starting from the file pointed by `FOS_CONFIG` if any.
+///
+/// `FOS_LOCATOR`, `FOS_PEERS` and `FOS_LISTENERS` take comma separated
+/// lists, `FOS_REQUEST_TIMEOUT` is in seconds.
+pub fn from_env() -> PyResult<SessionConfig> {
+    let mut config = match var("FOS_CONFIG") {
+        Some(path) => from_file(&path)?,
+        None => SessionConfig {
+            reconnect: true,
+            ..Default::default()
+        },
+    };
+    let mut peers = list_var("FOS_LOCATOR");
+    peers.extend(list_var("FOS_PEERS"));
+    if !peers.is_empty() {
+        config.peers = peers;
+    }
+    let listeners = list_var("FOS_LISTENERS");
+    if !listeners.is_empty() {
+        config.listeners = listeners;
+    }
+    if let Some(mode) = var("FOS_MODE") {
+        config.mode = Some(mode);
+    }
+    if let Some(reconnect) = var("FOS_RECONNECT") {
+        config.reconnect = match reconnect.to_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => true,
+            "0" | "false" | "no" | "off" => false,
+            _ => return Err(invalid_var("FOS_RECONNECT", &reconnect)),
+        };
+    }
+    if let Some(timeout) = var("FOS_REQUEST_TIMEOUT") {
+        let secs = timeout
+            .parse::<f64>()
+            .map_err(|_| invalid_var("FOS_REQUEST_TIMEOUT", &timeout))?;
+        config.request_timeout = Some(to_duration(secs)?);
+    }
+    let mut strings = [
+        ("FOS_USER", &mut config.user),
+        ("FOS_PASSWORD", &mut config.password),
+        (
+            "FOS_TLS_ROOT_CA_CERTIFICATE",
+            &mut config.tls_root_ca_certificate,
+        ),
+        (
+            "FOS_TLS_CLIENT_CERTIFICATE",
+            &mut config.tls_client_certificate,
+        ),
+        (
+            "FOS_TLS_CLIENT_PRIVATE_KEY",
+            &mut config.tls_client_private_key,
+        ),
+    ];
+    for (name, field) in strings.iter_mut() {
+        if let Some(value) = var(name) {
+            **field = Some(value);
+        }
+    }
+    let mut uuids = [
+        ("FOS_SYSTEM_ID", &mut config.system_id),
+        ("FOS_TENANT_ID", &mut config.tenant_id),
+    ];
+    for (name, field) in uuids.iter_mut() {
+        if let Some(value) = var(name) {
+            **field = Some(Uuid::parse_str(&value).map_err(|_| invalid_var(name, &value))?);
+        }
+    }
+    Ok(config)
+}
+
+fn var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+fn list_var(name: &str) -> Vec<String> {
+    var(name)
+        .map(|value| {
+            value
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn invalid_var(name: &str, value: &str) -> PyErr {
+    PyErr::new::<exceptions::PyValueError, _>(format!("Invalid value {} for {}", value, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    const VARS: &[&str] = &[
+        "FOS_CONFIG",
+        "FOS_LOCATOR",
+        "FOS_PEERS",
+        "FOS_LISTENERS",
+        "FOS_MODE",
+        "FOS_RECONNECT",
+        "FOS_REQUEST_TIMEOUT",
+        "FOS_USER",
+        "FOS_PASSWORD",
+        "FOS_TLS_ROOT_CA_CERTIFICATE",
+        "FOS_TLS_CLIENT_CERTIFICATE",
+        "FOS_TLS_CLIENT_PRIVATE_KEY",
+        "FOS_SYSTEM_ID",
+        "FOS_TENANT_ID",
+    ];
+
+    // The environment is shared by the test threads
+    static ENV: Mutex<()> = Mutex::new(());
+
+    fn with_env<R>(vars: &[(&str, &str)], f: impl FnOnce() -> R) -> R {
+        let _guard = ENV.lock().unwrap_or_else(|err| err.into_inner());
+        for name in VARS {
+            std::env::remove_var(name);
+        }
+        for (name, value) in vars {
+            std::env::set_var(name, value);
+        }
+        let result = f();
+        for (name, _) in vars {
+            std::env::remove_var(name);
+        }
+        result
+    }
+
+    fn is_value_error(err: PyErr) -> bool {
+        Python::with_gil(|py| err.is_instance::<exceptions::PyValueError>(py))
+    }
+
+    #[test]
+    fn from_env_defaults() {
+        let config = with_env(&[], from_env).unwrap();
+        assert!(config.reconnect);
+        assert!(config.mode.is_none());
+        assert!(config.peers.is_empty());
+        assert!(config.request_timeout.is_none());
+        assert!(config.user.is_none());
+    }
+
+    #[test]
+    fn from_env_reads_variables() {
+        let config = with_env(
+            &[
+                ("FOS_LOCATOR", "tcp/10.0.0.1:7447"),
+                ("FOS_PEERS", "tcp/10.0.0.2:7447, tcp/10.0.0.3:7447,"),
+                ("FOS_LISTENERS", "tcp/0.0.0.0:7447"),
+                ("FOS_MODE", "peer"),
+                ("FOS_RECONNECT", "off"),
+                ("FOS_REQUEST_TIMEOUT", "2.5"),
+                ("FOS_USER", "fos"),
+                ("FOS_PASSWORD", "secret"),
+                ("FOS_TLS_ROOT_CA_CERTIFICATE", "ca.pem"),
+                ("FOS_SYSTEM_ID", "00000000-0000-0000-0000-000000000000"),
+            ],
+            from_env,
+        )
+        .unwrap();
+        assert_eq!(
+            config.peers,
+            vec![
+                "tcp/10.0.0.1:7447",
+                "tcp/10.0.0.2:7447",
+                "tcp/10.0.0.3:7447"
+            ]
+        );
+        assert_eq!(config.listeners, vec!["tcp/0.0.0.0:7447"]);
+        assert_eq!(config.mode.as_deref(), Some("peer"));
+        assert!(!config.reconnect);
+        assert_eq!(config.request_timeout, Some(Duration::from_millis(2500)));
+        assert_eq!(config.user.as_deref(), Some("fos"));
+        assert_eq!(config.password.as_deref(), Some("secret"));
+        assert_eq!(config.tls_root_ca_certificate.as_deref(), Some("ca.pem"));
+        assert_eq!(config.system_id, Some(Uuid::nil()));
+        assert!(config.tenant_id.is_none());
+    }
+
+    #[test]
+    fn from_env_ignores_empty_variables() {
+        let config = with_env(&[("FOS_MODE", ""), ("FOS_LOCATOR", "")], from_env).unwrap();
+        assert!(config.mode.is_none());
+        assert!(config.peers.is_empty());
+    }
+
+    #[test]
+    fn from_env_rejects_invalid_values() {
+        for (name, value) in &[
+            ("FOS_RECONNECT", "maybe"),
+            ("FOS_REQUEST_TIMEOUT", "soon"),
+            ("FOS_REQUEST_TIMEOUT", "-1"),
+            ("FOS_SYSTEM_ID", "not-a-uuid"),
+            ("FOS_TENANT_ID", "not-a-uuid"),
+        ] {
+            let err = with_env(&[(*name, *value)], from_env).unwrap_err();
+            assert!(is_value_error(err), "{}={}", name, value);
+        }
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_env_overrides_config_file() {
+        let path = std::env::temp_dir().join(format!("fos-config-{}.yaml", std::process::id()));
+        std::fs::write(
+            &path,
+            "locator: tcp/10.0.0.1:7447\nmode: client\nreconnect: false\nuser: fos\n",
+        )
+        .unwrap();
+        let config = with_env(
+            &[("FOS_CONFIG", path.to_str().unwrap()), ("FOS_MODE", "peer")],
+            from_env,
+        );
+        std::fs::remove_file(&path).unwrap();
+        let config = config.unwrap();
+        assert_eq!(config.peers, vec!["tcp/10.0.0.1:7447"]);
+        assert_eq!(config.mode.as_deref(), Some("peer"));
+        assert!(!config.reconnect);
+        assert_eq!(config.user.as_deref(), Some("fos"));
+    }
+}
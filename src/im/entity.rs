@@ -13,17 +13,16 @@
 
 use fog05_sdk::im::entity;
 use pyo3::prelude::*;
-use pyo3::PyObjectProtocol;
 
-use crate::{cbor_to_pyerr, json_to_pyerr, utf8_to_pyerr, yaml_to_pyerr};
+use crate::{cbor_to_pyerr, from_json, from_yaml, json_to_pyerr, yaml_to_pyerr};
 
-#[pyclass]
+#[pyclass(module = "fog05.im.entity")]
 #[derive(Clone, Debug)]
 pub struct EntityDescriptor {
     pub d: entity::EntityDescriptor,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.entity")]
 #[derive(Clone, Debug)]
 pub struct EntityRecord {
     pub r: entity::EntityRecord,
@@ -62,10 +61,7 @@ impl EntityDescriptor {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<EntityDescriptor> {
-        let d = serde_json::from_str::<entity::EntityDescriptor>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let d = from_json::<entity::EntityDescriptor>(raw_data)?;
         Ok(EntityDescriptor { d })
     }
 
@@ -77,28 +73,12 @@ impl EntityDescriptor {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<EntityDescriptor> {
-        let d = serde_yaml::from_str::<entity::EntityDescriptor>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let d = from_yaml::<entity::EntityDescriptor>(raw_data)?;
         Ok(EntityDescriptor { d })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for EntityDescriptor {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.d))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(EntityDescriptor, d, entity::EntityDescriptor);
 
 #[pymethods]
 impl EntityRecord {
@@ -124,10 +104,7 @@ impl EntityRecord {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<EntityRecord> {
-        let r = serde_json::from_str::<entity::EntityRecord>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let r = from_json::<entity::EntityRecord>(raw_data)?;
         Ok(EntityRecord { r })
     }
 
@@ -139,25 +116,9 @@ impl EntityRecord {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<EntityRecord> {
-        let r = serde_yaml::from_str::<entity::EntityRecord>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let r = from_yaml::<entity::EntityRecord>(raw_data)?;
         Ok(EntityRecord { r })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for EntityRecord {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.r))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(EntityRecord, r, entity::EntityRecord);
@@ -12,27 +12,132 @@
 *********************************************************************************/
 
 use fog05_sdk::im::fdu;
+use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
-use pyo3::PyObjectProtocol;
+use pyo3::PyClass;
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
 
-use crate::{cbor_to_pyerr, json_to_pyerr, utf8_to_pyerr, yaml_to_pyerr};
+use crate::im::{parse_variant, variant_name};
+use crate::{cbor_to_pyerr, from_json, from_yaml, json_to_pyerr, parse_uuid, yaml_to_pyerr};
 
-#[pyclass]
+/// An FDU descriptor, every field is a property.
+///
+/// Nested objects write their changes back to the descriptor they were read
+/// from, lists are new Python lists, so adding or removing items takes an
+/// assignment:
+///
+/// ```python
+/// d.interfaces[0].name = "eth0"
+/// d.interfaces = d.interfaces + [interface]
+/// ```
+#[pyclass(module = "fog05.im.fdu")]
 #[derive(Clone, Debug)]
 pub struct FduDescriptor {
     pub d: fdu::FDUDescriptor,
 }
 
-#[pyclass]
+/// An FDU instance record, nested objects and lists behave like in
+/// `FduDescriptor`.
+#[pyclass(module = "fog05.im.fdu")]
 #[derive(Clone, Debug)]
 pub struct FduRecord {
     pub r: fdu::FDURecord,
 }
 
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct InterfaceRecord {
+    pub t: fdu::InterfaceRecord,
+    parent: Option<Parent<fdu::InterfaceRecord>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct ConnectionPointRecord {
+    pub t: fdu::ConnectionPointRecord,
+    parent: Option<Parent<fdu::ConnectionPointRecord>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct Image {
+    pub t: fdu::Image,
+    parent: Option<Parent<fdu::Image>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct ComputationalRequirements {
+    pub t: fdu::ComputationalRequirements,
+    parent: Option<Parent<fdu::ComputationalRequirements>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct Position {
+    pub t: fdu::Position,
+    parent: Option<Parent<fdu::Position>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct Proximity {
+    pub t: fdu::Proximity,
+    parent: Option<Parent<fdu::Proximity>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct GeographicalRequirements {
+    pub t: fdu::GeographicalRequirements,
+    parent: Option<Parent<fdu::GeographicalRequirements>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    pub t: fdu::Configuration,
+    parent: Option<Parent<fdu::Configuration>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct InterfaceDescriptor {
+    pub t: fdu::Interface,
+    parent: Option<Parent<fdu::Interface>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct StorageDescriptor {
+    pub t: fdu::StorageDescriptor,
+    parent: Option<Parent<fdu::StorageDescriptor>>,
+}
+
+#[pyclass(module = "fog05.im.fdu")]
+#[derive(Clone, Debug)]
+pub struct ConnectionPointDescriptor {
+    pub t: fdu::ConnectionPointDescriptor,
+    parent: Option<Parent<fdu::ConnectionPointDescriptor>>,
+}
+
 #[pymodule]
 pub fn fdu(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<FduDescriptor>()?;
     m.add_class::<FduRecord>()?;
+    m.add_class::<InterfaceRecord>()?;
+    m.add_class::<ConnectionPointRecord>()?;
+    m.add_class::<Image>()?;
+    m.add_class::<ComputationalRequirements>()?;
+    m.add_class::<Position>()?;
+    m.add_class::<Proximity>()?;
+    m.add_class::<GeographicalRequirements>()?;
+    m.add_class::<Configuration>()?;
+    m.add_class::<InterfaceDescriptor>()?;
+    m.add_class::<StorageDescriptor>()?;
+    m.add_class::<ConnectionPointDescriptor>()?;
 
     Ok(())
 }
@@ -61,10 +166,7 @@ impl FduDescriptor {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<FduDescriptor> {
-        let d = serde_json::from_str::<fdu::FDUDescriptor>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let d = from_json::<fdu::FDUDescriptor>(raw_data)?;
         Ok(FduDescriptor { d })
     }
 
@@ -76,29 +178,266 @@ impl FduDescriptor {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<FduDescriptor> {
-        let d = serde_yaml::from_str::<fdu::FDUDescriptor>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let d = from_yaml::<fdu::FDUDescriptor>(raw_data)?;
         Ok(FduDescriptor { d })
     }
-}
 
-#[pyproto]
-impl PyObjectProtocol for FduDescriptor {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.d))
+    #[getter]
+    fn uuid(&self) -> Option<String> {
+        self.d.uuid.map(|id| id.to_string())
+    }
+
+    #[setter]
+    fn set_uuid(&mut self, uuid: Option<String>) -> PyResult<()> {
+        self.d.uuid = uuid.as_deref().map(parse_uuid).transpose()?;
+        Ok(())
+    }
+
+    #[getter]
+    fn id(&self) -> String {
+        self.d.id.clone()
+    }
+
+    #[setter]
+    fn set_id(&mut self, id: String) {
+        self.d.id = id;
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.d.name.clone()
+    }
+
+    #[setter]
+    fn set_name(&mut self, name: String) {
+        self.d.name = name;
+    }
+
+    #[getter]
+    fn version(&self) -> String {
+        self.d.version.clone()
+    }
+
+    #[setter]
+    fn set_version(&mut self, version: String) {
+        self.d.version = version;
+    }
+
+    #[getter]
+    fn fdu_version(&self) -> String {
+        self.d.fdu_version.clone()
+    }
+
+    #[setter]
+    fn set_fdu_version(&mut self, fdu_version: String) {
+        self.d.fdu_version = fdu_version;
+    }
+
+    #[getter]
+    fn description(&self) -> Option<String> {
+        self.d.description.clone()
+    }
+
+    #[setter]
+    fn set_description(&mut self, description: Option<String>) {
+        self.d.description = description;
+    }
+
+    #[getter]
+    fn hypervisor(&self) -> String {
+        self.d.hypervisor.clone()
+    }
+
+    #[setter]
+    fn set_hypervisor(&mut self, hypervisor: String) {
+        self.d.hypervisor = hypervisor;
+    }
+
+    #[getter]
+    fn image(slf: PyRef<Self>) -> Option<Image> {
+        let image = slf.d.image.clone();
+        let obj = owner(slf);
+        image.map(|t| Image {
+            t,
+            parent: Parent::new(obj, |obj, t| {
+                borrow_mut::<FduDescriptor>(obj)?.d.image = Some(t);
+                Ok(())
+            }),
+        })
+    }
+
+    #[setter]
+    fn set_image(&mut self, image: Option<Image>) {
+        self.d.image = image.map(|i| i.t);
     }
 
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
+    #[getter]
+    fn hypervisor_specific(&self) -> Option<String> {
+        self.d.hypervisor_specific.clone()
     }
 
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
+    #[setter]
+    fn set_hypervisor_specific(&mut self, hypervisor_specific: Option<String>) {
+        self.d.hypervisor_specific = hypervisor_specific;
+    }
+
+    #[getter]
+    fn computation_requirements(slf: PyRef<Self>) -> ComputationalRequirements {
+        let t = slf.d.computation_requirements.clone();
+        ComputationalRequirements {
+            t,
+            parent: Parent::new(owner(slf), |obj, t| {
+                borrow_mut::<FduDescriptor>(obj)?.d.computation_requirements = t;
+                Ok(())
+            }),
+        }
+    }
+
+    #[setter]
+    fn set_computation_requirements(&mut self, requirements: ComputationalRequirements) {
+        self.d.computation_requirements = requirements.t;
+    }
+
+    #[getter]
+    fn geographical_requirements(slf: PyRef<Self>) -> Option<GeographicalRequirements> {
+        let requirements = slf.d.geographical_requirements.clone();
+        let obj = owner(slf);
+        requirements.map(|t| GeographicalRequirements {
+            t,
+            parent: Parent::new(obj, |obj, t| {
+                borrow_mut::<FduDescriptor>(obj)?
+                    .d
+                    .geographical_requirements = Some(t);
+                Ok(())
+            }),
+        })
+    }
+
+    #[setter]
+    fn set_geographical_requirements(&mut self, requirements: Option<GeographicalRequirements>) {
+        self.d.geographical_requirements = requirements.map(|r| r.t);
+    }
+
+    #[getter]
+    fn interfaces(slf: PyRef<Self>) -> Vec<InterfaceDescriptor> {
+        let items = slf.d.interfaces.clone();
+        let obj = owner(slf);
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| InterfaceDescriptor {
+                t,
+                parent: Parent::new(obj.clone(), move |obj, t| {
+                    set_at(&mut borrow_mut::<FduDescriptor>(obj)?.d.interfaces, i, t)
+                }),
+            })
+            .collect()
+    }
+
+    #[setter]
+    fn set_interfaces(&mut self, interfaces: Vec<InterfaceDescriptor>) {
+        self.d.interfaces = interfaces.into_iter().map(|i| i.t).collect();
+    }
+
+    #[getter]
+    fn storage(slf: PyRef<Self>) -> Vec<StorageDescriptor> {
+        let items = slf.d.storage.clone();
+        let obj = owner(slf);
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| StorageDescriptor {
+                t,
+                parent: Parent::new(obj.clone(), move |obj, t| {
+                    set_at(&mut borrow_mut::<FduDescriptor>(obj)?.d.storage, i, t)
+                }),
+            })
+            .collect()
+    }
+
+    #[setter]
+    fn set_storage(&mut self, storage: Vec<StorageDescriptor>) {
+        self.d.storage = storage.into_iter().map(|s| s.t).collect();
+    }
+
+    #[getter]
+    fn connection_points(slf: PyRef<Self>) -> Vec<ConnectionPointDescriptor> {
+        let items = slf.d.connection_points.clone();
+        let obj = owner(slf);
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| ConnectionPointDescriptor {
+                t,
+                parent: Parent::new(obj.clone(), move |obj, t| {
+                    set_at(
+                        &mut borrow_mut::<FduDescriptor>(obj)?.d.connection_points,
+                        i,
+                        t,
+                    )
+                }),
+            })
+            .collect()
+    }
+
+    #[setter]
+    fn set_connection_points(&mut self, connection_points: Vec<ConnectionPointDescriptor>) {
+        self.d.connection_points = connection_points.into_iter().map(|cp| cp.t).collect();
+    }
+
+    #[getter]
+    fn configuration(slf: PyRef<Self>) -> Option<Configuration> {
+        let configuration = slf.d.configuration.clone();
+        let obj = owner(slf);
+        configuration.map(|t| Configuration {
+            t,
+            parent: Parent::new(obj, |obj, t| {
+                borrow_mut::<FduDescriptor>(obj)?.d.configuration = Some(t);
+                Ok(())
+            }),
+        })
+    }
+
+    #[setter]
+    fn set_configuration(&mut self, configuration: Option<Configuration>) {
+        self.d.configuration = configuration.map(|c| c.t);
+    }
+
+    /// `"LIVE"` or `"COLD"`.
+    #[getter]
+    fn migration_kind(&self) -> String {
+        variant_name(&self.d.migration_kind)
+    }
+
+    #[setter]
+    fn set_migration_kind(&mut self, migration_kind: &str) -> PyResult<()> {
+        self.d.migration_kind = parse_variant(migration_kind)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn replicas(&self) -> Option<u8> {
+        self.d.replicas
+    }
+
+    #[setter]
+    fn set_replicas(&mut self, replicas: Option<u8>) {
+        self.d.replicas = replicas;
+    }
+
+    #[getter]
+    fn depends_on(&self) -> Vec<String> {
+        self.d.depends_on.clone()
+    }
+
+    #[setter]
+    fn set_depends_on(&mut self, depends_on: Vec<String>) {
+        self.d.depends_on = depends_on;
     }
 }
 
+im_wrapper!(FduDescriptor, d, fdu::FDUDescriptor);
+
 impl pyo3::conversion::ToPyObject for FduDescriptor {
     fn to_object(&self, py: Python) -> pyo3::PyObject {
         pyo3::IntoPy::into_py(pyo3::Py::new(py, self.clone()).unwrap(), py)
@@ -107,6 +446,174 @@ impl pyo3::conversion::ToPyObject for FduDescriptor {
 
 #[pymethods]
 impl FduRecord {
+    /// Builds a record, e.g. from a hypervisor plugin `define_fdu`:
+    ///
+    /// ```python
+    /// FduRecord(fdu_uuid=descriptor.uuid, node=node_uuid, status="DEFINED")
+    /// ```
+    ///
+    /// A random `uuid` is generated when not given.
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[args(
+        "*",
+        fdu_uuid,
+        node,
+        uuid = "None",
+        status = "\"DEFINED\"",
+        interfaces = "vec![]",
+        connection_points = "vec![]",
+        error = "None",
+        hypervisor_specific = "None",
+        restarts = "0"
+    )]
+    fn new(
+        fdu_uuid: String,
+        node: String,
+        uuid: Option<String>,
+        status: &str,
+        interfaces: Vec<InterfaceRecord>,
+        connection_points: Vec<ConnectionPointRecord>,
+        error: Option<String>,
+        hypervisor_specific: Option<Vec<u8>>,
+        restarts: u32,
+    ) -> PyResult<Self> {
+        let uuid = match uuid {
+            Some(id) => parse_uuid(&id)?,
+            None => Uuid::new_v4(),
+        };
+        Ok(FduRecord {
+            r: fdu::FDURecord {
+                uuid,
+                fdu_uuid: parse_uuid(&fdu_uuid)?,
+                node: parse_uuid(&node)?,
+                interfaces: interfaces.into_iter().map(|i| i.t).collect(),
+                connection_points: connection_points.into_iter().map(|cp| cp.t).collect(),
+                status: parse_variant(status)?,
+                error,
+                hypervisor_specific,
+                restarts,
+            },
+        })
+    }
+
+    #[getter]
+    fn uuid(&self) -> String {
+        self.r.uuid.to_string()
+    }
+
+    #[setter]
+    fn set_uuid(&mut self, uuid: &str) -> PyResult<()> {
+        self.r.uuid = parse_uuid(uuid)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn fdu_uuid(&self) -> String {
+        self.r.fdu_uuid.to_string()
+    }
+
+    #[setter]
+    fn set_fdu_uuid(&mut self, fdu_uuid: &str) -> PyResult<()> {
+        self.r.fdu_uuid = parse_uuid(fdu_uuid)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn node(&self) -> String {
+        self.r.node.to_string()
+    }
+
+    #[setter]
+    fn set_node(&mut self, node: &str) -> PyResult<()> {
+        self.r.node = parse_uuid(node)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn interfaces(slf: PyRef<Self>) -> Vec<InterfaceRecord> {
+        let items = slf.r.interfaces.clone();
+        let obj = owner(slf);
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| InterfaceRecord {
+                t,
+                parent: Parent::new(obj.clone(), move |obj, t| {
+                    set_at(&mut borrow_mut::<FduRecord>(obj)?.r.interfaces, i, t)
+                }),
+            })
+            .collect()
+    }
+
+    #[setter]
+    fn set_interfaces(&mut self, interfaces: Vec<InterfaceRecord>) {
+        self.r.interfaces = interfaces.into_iter().map(|i| i.t).collect();
+    }
+
+    #[getter]
+    fn connection_points(slf: PyRef<Self>) -> Vec<ConnectionPointRecord> {
+        let items = slf.r.connection_points.clone();
+        let obj = owner(slf);
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| ConnectionPointRecord {
+                t,
+                parent: Parent::new(obj.clone(), move |obj, t| {
+                    set_at(&mut borrow_mut::<FduRecord>(obj)?.r.connection_points, i, t)
+                }),
+            })
+            .collect()
+    }
+
+    #[setter]
+    fn set_connection_points(&mut self, connection_points: Vec<ConnectionPointRecord>) {
+        self.r.connection_points = connection_points.into_iter().map(|cp| cp.t).collect();
+    }
+
+    /// The instance state, e.g. `"DEFINED"`, `"CONFIGURED"` or `"RUNNING"`.
+    #[getter]
+    fn status(&self) -> String {
+        variant_name(&self.r.status)
+    }
+
+    #[setter]
+    fn set_status(&mut self, status: &str) -> PyResult<()> {
+        self.r.status = parse_variant(status)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn error(&self) -> Option<String> {
+        self.r.error.clone()
+    }
+
+    #[setter]
+    fn set_error(&mut self, error: Option<String>) {
+        self.r.error = error;
+    }
+
+    #[getter]
+    fn hypervisor_specific(&self) -> Option<Vec<u8>> {
+        self.r.hypervisor_specific.clone()
+    }
+
+    #[setter]
+    fn set_hypervisor_specific(&mut self, hypervisor_specific: Option<Vec<u8>>) {
+        self.r.hypervisor_specific = hypervisor_specific;
+    }
+
+    #[getter]
+    fn restarts(&self) -> u32 {
+        self.r.restarts
+    }
+
+    #[setter]
+    fn set_restarts(&mut self, restarts: u32) {
+        self.r.restarts = restarts;
+    }
+
     #[cfg(feature = "cbor")]
     fn serialize(&self) -> PyResult<Vec<u8>> {
         serde_cbor::to_vec(&self.r).map_err(cbor_to_pyerr)
@@ -129,10 +636,7 @@ impl FduRecord {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<FduRecord> {
-        let r = serde_json::from_str::<fdu::FDURecord>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let r = from_json::<fdu::FDURecord>(raw_data)?;
         Ok(FduRecord { r })
     }
 
@@ -144,31 +648,966 @@ impl FduRecord {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<FduRecord> {
-        let r = serde_yaml::from_str::<fdu::FDURecord>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let r = from_yaml::<fdu::FDURecord>(raw_data)?;
         Ok(FduRecord { r })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for FduRecord {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.r))
+im_wrapper!(FduRecord, r, fdu::FDURecord);
+
+impl pyo3::conversion::ToPyObject for FduRecord {
+    fn to_object(&self, py: Python) -> pyo3::PyObject {
+        pyo3::IntoPy::into_py(pyo3::Py::new(py, self.clone()).unwrap(), py)
+    }
+}
+#[pymethods]
+impl InterfaceRecord {
+    #[new]
+    #[args(mac_address = "None", cp_uuid = "None")]
+    fn new(
+        name: String,
+        kind: &str,
+        intf_uuid: &str,
+        virtual_interface: crate::im::net::VirtualInterface,
+        mac_address: Option<String>,
+        cp_uuid: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(InterfaceRecord {
+            t: fdu::InterfaceRecord {
+                name,
+                kind: parse_variant(kind)?,
+                mac_address: mac_address.as_deref().map(parse_from_str).transpose()?,
+                cp_uuid: cp_uuid.as_deref().map(parse_uuid).transpose()?,
+                intf_uuid: parse_uuid(intf_uuid)?,
+                virtual_interface: virtual_interface.t,
+            },
+            parent: None,
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.t.name.clone()
+    }
+
+    #[setter]
+    fn set_name(&mut self, name: String) -> PyResult<()> {
+        self.t.name = name;
+        self.sync()
+    }
+
+    #[getter]
+    fn kind(&self) -> String {
+        variant_name(&self.t.kind)
+    }
+
+    #[setter]
+    fn set_kind(&mut self, kind: &str) -> PyResult<()> {
+        self.t.kind = parse_variant(kind)?;
+        self.sync()
+    }
+
+    #[getter]
+    fn mac_address(&self) -> Option<String> {
+        self.t.mac_address.as_ref().map(|mac| mac.to_string())
+    }
+
+    #[setter]
+    fn set_mac_address(&mut self, mac_address: Option<String>) -> PyResult<()> {
+        self.t.mac_address = mac_address.as_deref().map(parse_from_str).transpose()?;
+        self.sync()
+    }
+
+    #[getter]
+    fn cp_uuid(&self) -> Option<String> {
+        self.t.cp_uuid.map(|id| id.to_string())
+    }
+
+    #[setter]
+    fn set_cp_uuid(&mut self, cp_uuid: Option<String>) -> PyResult<()> {
+        self.t.cp_uuid = cp_uuid.as_deref().map(parse_uuid).transpose()?;
+        self.sync()
+    }
+
+    #[getter]
+    fn intf_uuid(&self) -> String {
+        self.t.intf_uuid.to_string()
+    }
+
+    #[setter]
+    fn set_intf_uuid(&mut self, intf_uuid: &str) -> PyResult<()> {
+        self.t.intf_uuid = parse_uuid(intf_uuid)?;
+        self.sync()
+    }
+
+    #[getter]
+    fn virtual_interface(&self) -> crate::im::net::VirtualInterface {
+        crate::im::net::VirtualInterface {
+            t: self.t.virtual_interface.clone(),
+        }
+    }
+
+    #[setter]
+    fn set_virtual_interface(
+        &mut self,
+        virtual_interface: crate::im::net::VirtualInterface,
+    ) -> PyResult<()> {
+        self.t.virtual_interface = virtual_interface.t;
+        self.sync()
+    }
+}
+
+im_wrapper!(InterfaceRecord, t, fdu::InterfaceRecord, parent);
+
+#[pymethods]
+impl ConnectionPointRecord {
+    #[new]
+    fn new(uuid: &str, id: String) -> PyResult<Self> {
+        Ok(ConnectionPointRecord {
+            t: fdu::ConnectionPointRecord {
+                uuid: parse_uuid(uuid)?,
+                id,
+            },
+            parent: None,
+        })
+    }
+
+    #[getter]
+    fn uuid(&self) -> String {
+        self.t.uuid.to_string()
+    }
+
+    #[setter]
+    fn set_uuid(&mut self, uuid: &str) -> PyResult<()> {
+        self.t.uuid = parse_uuid(uuid)?;
+        self.sync()
     }
 
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
+    #[getter]
+    fn id(&self) -> String {
+        self.t.id.clone()
     }
 
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
+    #[setter]
+    fn set_id(&mut self, id: String) -> PyResult<()> {
+        self.t.id = id;
+        self.sync()
     }
 }
 
-impl pyo3::conversion::ToPyObject for FduRecord {
-    fn to_object(&self, py: Python) -> pyo3::PyObject {
-        pyo3::IntoPy::into_py(pyo3::Py::new(py, self.clone()).unwrap(), py)
+im_wrapper!(ConnectionPointRecord, t, fdu::ConnectionPointRecord, parent);
+
+#[pymethods]
+impl Image {
+    #[new]
+    #[args(uuid = "None", name = "None")]
+    fn new(
+        uri: String,
+        checksum: String,
+        format: String,
+        uuid: Option<String>,
+        name: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(Image {
+            t: fdu::Image {
+                uuid: uuid.as_deref().map(parse_uuid).transpose()?,
+                name,
+                uri,
+                checksum,
+                format,
+            },
+            parent: None,
+        })
+    }
+
+    #[getter]
+    fn uuid(&self) -> Option<String> {
+        self.t.uuid.map(|id| id.to_string())
+    }
+
+    #[setter]
+    fn set_uuid(&mut self, uuid: Option<String>) -> PyResult<()> {
+        self.t.uuid = uuid.as_deref().map(parse_uuid).transpose()?;
+        self.sync()
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.t.name.clone()
+    }
+
+    #[setter]
+    fn set_name(&mut self, name: Option<String>) -> PyResult<()> {
+        self.t.name = name;
+        self.sync()
+    }
+
+    #[getter]
+    fn uri(&self) -> String {
+        self.t.uri.clone()
+    }
+
+    #[setter]
+    fn set_uri(&mut self, uri: String) -> PyResult<()> {
+        self.t.uri = uri;
+        self.sync()
+    }
+
+    #[getter]
+    fn checksum(&self) -> String {
+        self.t.checksum.clone()
+    }
+
+    #[setter]
+    fn set_checksum(&mut self, checksum: String) -> PyResult<()> {
+        self.t.checksum = checksum;
+        self.sync()
+    }
+
+    #[getter]
+    fn format(&self) -> String {
+        self.t.format.clone()
+    }
+
+    #[setter]
+    fn set_format(&mut self, format: String) -> PyResult<()> {
+        self.t.format = format;
+        self.sync()
+    }
+}
+
+im_wrapper!(Image, t, fdu::Image, parent);
+
+#[pymethods]
+impl ComputationalRequirements {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[args(operating_system = "None")]
+    fn new(
+        cpu_arch: String,
+        cpu_min_freq: u64,
+        cpu_min_count: u8,
+        gpu_min_count: u8,
+        fpga_min_count: u8,
+        ram_size_mb: u32,
+        storage_size_mb: u32,
+        operating_system: Option<String>,
+    ) -> Self {
+        ComputationalRequirements {
+            t: fdu::ComputationalRequirements {
+                cpu_arch,
+                cpu_min_freq,
+                cpu_min_count,
+                gpu_min_count,
+                fpga_min_count,
+                operating_system,
+                ram_size_mb,
+                storage_size_mb,
+            },
+            parent: None,
+        }
+    }
+
+    #[getter]
+    fn cpu_arch(&self) -> String {
+        self.t.cpu_arch.clone()
+    }
+
+    #[setter]
+    fn set_cpu_arch(&mut self, cpu_arch: String) -> PyResult<()> {
+        self.t.cpu_arch = cpu_arch;
+        self.sync()
+    }
+
+    #[getter]
+    fn cpu_min_freq(&self) -> u64 {
+        self.t.cpu_min_freq
+    }
+
+    #[setter]
+    fn set_cpu_min_freq(&mut self, cpu_min_freq: u64) -> PyResult<()> {
+        self.t.cpu_min_freq = cpu_min_freq;
+        self.sync()
+    }
+
+    #[getter]
+    fn cpu_min_count(&self) -> u8 {
+        self.t.cpu_min_count
+    }
+
+    #[setter]
+    fn set_cpu_min_count(&mut self, cpu_min_count: u8) -> PyResult<()> {
+        self.t.cpu_min_count = cpu_min_count;
+        self.sync()
+    }
+
+    #[getter]
+    fn gpu_min_count(&self) -> u8 {
+        self.t.gpu_min_count
+    }
+
+    #[setter]
+    fn set_gpu_min_count(&mut self, gpu_min_count: u8) -> PyResult<()> {
+        self.t.gpu_min_count = gpu_min_count;
+        self.sync()
+    }
+
+    #[getter]
+    fn fpga_min_count(&self) -> u8 {
+        self.t.fpga_min_count
+    }
+
+    #[setter]
+    fn set_fpga_min_count(&mut self, fpga_min_count: u8) -> PyResult<()> {
+        self.t.fpga_min_count = fpga_min_count;
+        self.sync()
+    }
+
+    #[getter]
+    fn operating_system(&self) -> Option<String> {
+        self.t.operating_system.clone()
+    }
+
+    #[setter]
+    fn set_operating_system(&mut self, operating_system: Option<String>) -> PyResult<()> {
+        self.t.operating_system = operating_system;
+        self.sync()
+    }
+
+    #[getter]
+    fn ram_size_mb(&self) -> u32 {
+        self.t.ram_size_mb
+    }
+
+    #[setter]
+    fn set_ram_size_mb(&mut self, ram_size_mb: u32) -> PyResult<()> {
+        self.t.ram_size_mb = ram_size_mb;
+        self.sync()
+    }
+
+    #[getter]
+    fn storage_size_mb(&self) -> u32 {
+        self.t.storage_size_mb
+    }
+
+    #[setter]
+    fn set_storage_size_mb(&mut self, storage_size_mb: u32) -> PyResult<()> {
+        self.t.storage_size_mb = storage_size_mb;
+        self.sync()
+    }
+}
+
+im_wrapper!(
+    ComputationalRequirements,
+    t,
+    fdu::ComputationalRequirements,
+    parent
+);
+
+#[pymethods]
+impl Position {
+    #[new]
+    fn new(lat: String, lon: String, radius: f64) -> Self {
+        Position {
+            t: fdu::Position { lat, lon, radius },
+            parent: None,
+        }
+    }
+
+    #[getter]
+    fn lat(&self) -> String {
+        self.t.lat.clone()
+    }
+
+    #[setter]
+    fn set_lat(&mut self, lat: String) -> PyResult<()> {
+        self.t.lat = lat;
+        self.sync()
+    }
+
+    #[getter]
+    fn lon(&self) -> String {
+        self.t.lon.clone()
+    }
+
+    #[setter]
+    fn set_lon(&mut self, lon: String) -> PyResult<()> {
+        self.t.lon = lon;
+        self.sync()
+    }
+
+    #[getter]
+    fn radius(&self) -> f64 {
+        self.t.radius
+    }
+
+    #[setter]
+    fn set_radius(&mut self, radius: f64) -> PyResult<()> {
+        self.t.radius = radius;
+        self.sync()
+    }
+}
+
+im_wrapper!(Position, t, fdu::Position, parent);
+
+#[pymethods]
+impl Proximity {
+    #[new]
+    fn new(neighbour: String, radius: f64) -> Self {
+        Proximity {
+            t: fdu::Proximity { neighbour, radius },
+            parent: None,
+        }
+    }
+
+    #[getter]
+    fn neighbour(&self) -> String {
+        self.t.neighbour.clone()
+    }
+
+    #[setter]
+    fn set_neighbour(&mut self, neighbour: String) -> PyResult<()> {
+        self.t.neighbour = neighbour;
+        self.sync()
+    }
+
+    #[getter]
+    fn radius(&self) -> f64 {
+        self.t.radius
+    }
+
+    #[setter]
+    fn set_radius(&mut self, radius: f64) -> PyResult<()> {
+        self.t.radius = radius;
+        self.sync()
+    }
+}
+
+im_wrapper!(Proximity, t, fdu::Proximity, parent);
+
+#[pymethods]
+impl GeographicalRequirements {
+    #[new]
+    #[args(position = "None", proximity = "None")]
+    fn new(position: Option<Position>, proximity: Option<Vec<Proximity>>) -> Self {
+        GeographicalRequirements {
+            t: fdu::GeographicalRequirements {
+                position: position.map(|w| w.t),
+                proximity: proximity.map(|v| v.into_iter().map(|w| w.t).collect()),
+            },
+            parent: None,
+        }
+    }
+
+    #[getter]
+    fn position(slf: PyRef<Self>) -> Option<Position> {
+        let position = slf.t.position.clone();
+        let obj = owner(slf);
+        position.map(|t| Position {
+            t,
+            parent: Parent::new(obj, |obj, t| {
+                let mut requirements = borrow_mut::<GeographicalRequirements>(obj)?;
+                requirements.t.position = Some(t);
+                requirements.sync()
+            }),
+        })
+    }
+
+    #[setter]
+    fn set_position(&mut self, position: Option<Position>) -> PyResult<()> {
+        self.t.position = position.map(|w| w.t);
+        self.sync()
+    }
+
+    #[getter]
+    fn proximity(slf: PyRef<Self>) -> Option<Vec<Proximity>> {
+        let proximity = slf.t.proximity.clone();
+        let obj = owner(slf);
+        proximity.map(|v| {
+            v.into_iter()
+                .enumerate()
+                .map(|(i, t)| Proximity {
+                    t,
+                    parent: Parent::new(obj.clone(), move |obj, t| {
+                        let mut requirements = borrow_mut::<GeographicalRequirements>(obj)?;
+                        match requirements.t.proximity.as_mut() {
+                            Some(proximity) => set_at(proximity, i, t)?,
+                            None => return Err(PyIndexError::new_err("proximity was removed")),
+                        }
+                        requirements.sync()
+                    }),
+                })
+                .collect()
+        })
+    }
+
+    #[setter]
+    fn set_proximity(&mut self, proximity: Option<Vec<Proximity>>) -> PyResult<()> {
+        self.t.proximity = proximity.map(|v| v.into_iter().map(|w| w.t).collect());
+        self.sync()
+    }
+}
+
+im_wrapper!(
+    GeographicalRequirements,
+    t,
+    fdu::GeographicalRequirements,
+    parent
+);
+
+#[pymethods]
+impl Configuration {
+    #[new]
+    #[args(ssh_keys = "None")]
+    fn new(conf_type: String, script: String, ssh_keys: Option<Vec<String>>) -> PyResult<Self> {
+        Ok(Configuration {
+            t: fdu::Configuration {
+                conf_type: parse_variant(&conf_type)?,
+                script,
+                ssh_keys,
+            },
+            parent: None,
+        })
+    }
+
+    #[getter]
+    fn conf_type(&self) -> String {
+        variant_name(&self.t.conf_type)
+    }
+
+    #[setter]
+    fn set_conf_type(&mut self, conf_type: &str) -> PyResult<()> {
+        self.t.conf_type = parse_variant(conf_type)?;
+        self.sync()
+    }
+
+    #[getter]
+    fn script(&self) -> String {
+        self.t.script.clone()
+    }
+
+    #[setter]
+    fn set_script(&mut self, script: String) -> PyResult<()> {
+        self.t.script = script;
+        self.sync()
+    }
+
+    #[getter]
+    fn ssh_keys(&self) -> Option<Vec<String>> {
+        self.t.ssh_keys.clone()
+    }
+
+    #[setter]
+    fn set_ssh_keys(&mut self, ssh_keys: Option<Vec<String>>) -> PyResult<()> {
+        self.t.ssh_keys = ssh_keys;
+        self.sync()
+    }
+}
+
+im_wrapper!(Configuration, t, fdu::Configuration, parent);
+
+#[pymethods]
+impl InterfaceDescriptor {
+    #[new]
+    #[args(mac_address = "None", cp_id = "None")]
+    fn new(
+        name: String,
+        kind: String,
+        mac_address: Option<String>,
+        cp_id: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(InterfaceDescriptor {
+            t: fdu::Interface {
+                name,
+                kind: parse_variant(&kind)?,
+                mac_address: mac_address.as_deref().map(parse_from_str).transpose()?,
+                cp_id,
+            },
+            parent: None,
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.t.name.clone()
+    }
+
+    #[setter]
+    fn set_name(&mut self, name: String) -> PyResult<()> {
+        self.t.name = name;
+        self.sync()
+    }
+
+    #[getter]
+    fn kind(&self) -> String {
+        variant_name(&self.t.kind)
+    }
+
+    #[setter]
+    fn set_kind(&mut self, kind: &str) -> PyResult<()> {
+        self.t.kind = parse_variant(kind)?;
+        self.sync()
+    }
+
+    #[getter]
+    fn mac_address(&self) -> Option<String> {
+        self.t.mac_address.as_ref().map(|mac| mac.to_string())
+    }
+
+    #[setter]
+    fn set_mac_address(&mut self, mac_address: Option<String>) -> PyResult<()> {
+        self.t.mac_address = mac_address.as_deref().map(parse_from_str).transpose()?;
+        self.sync()
+    }
+
+    #[getter]
+    fn cp_id(&self) -> Option<String> {
+        self.t.cp_id.clone()
+    }
+
+    #[setter]
+    fn set_cp_id(&mut self, cp_id: Option<String>) -> PyResult<()> {
+        self.t.cp_id = cp_id;
+        self.sync()
+    }
+}
+
+im_wrapper!(InterfaceDescriptor, t, fdu::Interface, parent);
+
+#[pymethods]
+impl StorageDescriptor {
+    #[new]
+    #[args(file_system_protocol = "None", cp_id = "None")]
+    fn new(
+        id: String,
+        storage_type: String,
+        size: u32,
+        file_system_protocol: Option<String>,
+        cp_id: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(StorageDescriptor {
+            t: fdu::StorageDescriptor {
+                id,
+                storage_type: parse_variant(&storage_type)?,
+                size,
+                file_system_protocol,
+                cp_id,
+            },
+            parent: None,
+        })
+    }
+
+    #[getter]
+    fn id(&self) -> String {
+        self.t.id.clone()
+    }
+
+    #[setter]
+    fn set_id(&mut self, id: String) -> PyResult<()> {
+        self.t.id = id;
+        self.sync()
+    }
+
+    #[getter]
+    fn storage_type(&self) -> String {
+        variant_name(&self.t.storage_type)
+    }
+
+    #[setter]
+    fn set_storage_type(&mut self, storage_type: &str) -> PyResult<()> {
+        self.t.storage_type = parse_variant(storage_type)?;
+        self.sync()
+    }
+
+    #[getter]
+    fn size(&self) -> u32 {
+        self.t.size
+    }
+
+    #[setter]
+    fn set_size(&mut self, size: u32) -> PyResult<()> {
+        self.t.size = size;
+        self.sync()
+    }
+
+    #[getter]
+    fn file_system_protocol(&self) -> Option<String> {
+        self.t.file_system_protocol.clone()
+    }
+
+    #[setter]
+    fn set_file_system_protocol(&mut self, file_system_protocol: Option<String>) -> PyResult<()> {
+        self.t.file_system_protocol = file_system_protocol;
+        self.sync()
+    }
+
+    #[getter]
+    fn cp_id(&self) -> Option<String> {
+        self.t.cp_id.clone()
+    }
+
+    #[setter]
+    fn set_cp_id(&mut self, cp_id: Option<String>) -> PyResult<()> {
+        self.t.cp_id = cp_id;
+        self.sync()
+    }
+}
+
+im_wrapper!(StorageDescriptor, t, fdu::StorageDescriptor, parent);
+
+#[pymethods]
+impl ConnectionPointDescriptor {
+    #[new]
+    #[args(uuid = "None", vld_ref = "None")]
+    fn new(
+        name: String,
+        id: String,
+        uuid: Option<String>,
+        vld_ref: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(ConnectionPointDescriptor {
+            t: fdu::ConnectionPointDescriptor {
+                uuid: uuid.as_deref().map(parse_uuid).transpose()?,
+                name,
+                id,
+                vld_ref,
+            },
+            parent: None,
+        })
+    }
+
+    #[getter]
+    fn uuid(&self) -> Option<String> {
+        self.t.uuid.map(|id| id.to_string())
+    }
+
+    #[setter]
+    fn set_uuid(&mut self, uuid: Option<String>) -> PyResult<()> {
+        self.t.uuid = uuid.as_deref().map(parse_uuid).transpose()?;
+        self.sync()
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.t.name.clone()
+    }
+
+    #[setter]
+    fn set_name(&mut self, name: String) -> PyResult<()> {
+        self.t.name = name;
+        self.sync()
+    }
+
+    #[getter]
+    fn id(&self) -> String {
+        self.t.id.clone()
+    }
+
+    #[setter]
+    fn set_id(&mut self, id: String) -> PyResult<()> {
+        self.t.id = id;
+        self.sync()
+    }
+
+    #[getter]
+    fn vld_ref(&self) -> Option<String> {
+        self.t.vld_ref.clone()
+    }
+
+    #[setter]
+    fn set_vld_ref(&mut self, vld_ref: Option<String>) -> PyResult<()> {
+        self.t.vld_ref = vld_ref;
+        self.sync()
+    }
+}
+
+im_wrapper!(
+    ConnectionPointDescriptor,
+    t,
+    fdu::ConnectionPointDescriptor,
+    parent
+);
+
+/// The object a nested object was read from, and how to write it back there.
+#[derive(Clone)]
+pub(crate) struct Parent<T> {
+    obj: PyObject,
+    write: Arc<dyn Fn(&PyAny, T) -> PyResult<()> + Send + Sync>,
+}
+
+impl<T> Parent<T> {
+    fn new<F>(obj: PyObject, write: F) -> Option<Self>
+    where
+        F: Fn(&PyAny, T) -> PyResult<()> + Send + Sync + 'static,
+    {
+        Some(Parent {
+            obj,
+            write: Arc::new(write),
+        })
+    }
+
+    fn write(&self, t: T) -> PyResult<()> {
+        Python::with_gil(|py| (self.write)(self.obj.as_ref(py), t))
+    }
+}
+
+impl<T> fmt::Debug for Parent<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Parent").field("obj", &self.obj).finish()
+    }
+}
+
+fn owner<T: PyClass>(slf: PyRef<T>) -> PyObject {
+    let py = slf.py();
+    Py::from(slf).into_py(py)
+}
+
+fn borrow_mut<T: PyClass>(obj: &PyAny) -> PyResult<PyRefMut<T>> {
+    Ok(obj.downcast::<PyCell<T>>()?.try_borrow_mut()?)
+}
+
+/// Replaces the `i`th item, unless the list got shorter since it was read.
+fn set_at<T>(items: &mut Vec<T>, i: usize, t: T) -> PyResult<()> {
+    match items.get_mut(i) {
+        Some(item) => {
+            *item = t;
+            Ok(())
+        }
+        None => Err(PyIndexError::new_err(format!("item {} was removed", i))),
+    }
+}
+
+fn parse_from_str<T>(value: &str) -> PyResult<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|err: T::Err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn position() -> Position {
+        Position::new("45.07".to_string(), "7.68".to_string(), 10.0)
+    }
+
+    /// Runs `code` with the wrapper classes and `vars` in scope.
+    fn run(py: Python, code: &str, vars: &[(&str, PyObject)]) {
+        let locals = PyDict::new(py);
+        locals
+            .set_item("Position", py.get_type::<Position>())
+            .unwrap();
+        locals
+            .set_item(
+                "GeographicalRequirements",
+                py.get_type::<GeographicalRequirements>(),
+            )
+            .unwrap();
+        for (name, value) in vars {
+            locals.set_item(name, value).unwrap();
+        }
+        if let Err(err) = py.run(code, None, Some(locals)) {
+            err.print(py);
+            panic!("{}", code);
+        }
+    }
+
+    #[test]
+    fn dict_round_trip() {
+        Python::with_gil(|py| {
+            let p = position();
+            let dict = p.to_dict(py).unwrap();
+            assert_eq!(Position::from_dict(dict.as_ref(py)).unwrap().t, p.t);
+            run(
+                py,
+                "assert d == {'lat': '45.07', 'lon': '7.68', 'radius': 10.0}",
+                &[("d", dict)],
+            );
+        });
+    }
+
+    #[test]
+    fn from_dict_reports_path() {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("lat", "45.07").unwrap();
+            dict.set_item("lon", "7.68").unwrap();
+            dict.set_item("radius", "far").unwrap();
+            let err = Position::from_dict(dict).unwrap_err();
+            assert!(err.is_instance::<crate::DescriptorParseError>(py));
+            let path: String = err.instance(py).getattr("path").unwrap().extract().unwrap();
+            assert_eq!(path, "radius");
+        });
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn reduce_round_trip() {
+        Python::with_gil(|py| {
+            let p = position();
+            let (from_state, args) = p.__reduce__(py).unwrap();
+            let copy = from_state.call1(py, args).unwrap();
+            assert_eq!(copy.extract::<Position>(py).unwrap().t, p.t);
+        });
+    }
+
+    #[test]
+    fn equality() {
+        Python::with_gil(|py| {
+            run(
+                py,
+                "\
+a = Position('45.07', '7.68', 10.0)
+b = Position('45.07', '7.68', 10.0)
+c = Position('45.07', '7.68', 20.0)
+assert a == b and not a != b
+assert a != c and not a == c
+try:
+    a < b
+    assert False, 'ordering is not supported'
+except TypeError:
+    pass
+",
+                &[],
+            );
+        });
+    }
+
+    #[test]
+    fn nested_objects_write_back() {
+        Python::with_gil(|py| {
+            run(
+                py,
+                "\
+g = GeographicalRequirements(Position('45.07', '7.68', 10.0))
+p = g.position
+p.radius = 20.0
+assert g.position.radius == 20.0
+",
+                &[],
+            );
+        });
+    }
+
+    #[test]
+    fn copies_are_detached() {
+        Python::with_gil(|py| {
+            run(
+                py,
+                "\
+import copy
+g = GeographicalRequirements(Position('45.07', '7.68', 10.0))
+p = copy.copy(g.position)
+p.radius = 20.0
+assert g.position.radius == 10.0
+",
+                &[],
+            );
+        });
     }
 }
@@ -11,8 +11,115 @@
 *   ADLINK fog05 team, <fog05@adlink-labs.tech>
 *********************************************************************************/
 
+use pyo3::basic::CompareOp;
+use pyo3::exceptions;
 use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
+use serde::de::value::{Error as ValueError, StrDeserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use std::fmt::Debug;
+
+/// Implements the methods shared by all the wrappers: `$name` holds the SDK
+/// value of type `$t` in its `$field` field.
+///
+/// Nested wrappers also name their `parent` field, a `fdu::Parent` that is
+/// reset for the new objects and updated by `sync`.
+macro_rules! im_wrapper {
+    ($name:ident, $field:ident, $t:ty, parent) => {
+        im_wrapper!(@impl $name, $field, $t, parent);
+    };
+    ($name:ident, $field:ident, $t:ty) => {
+        im_wrapper!(@impl $name, $field, $t);
+    };
+    (@impl $name:ident, $field:ident, $t:ty $(, $parent:ident)?) => {
+        $(
+            impl $name {
+                /// Writes the value back to the object it was read from, if any.
+                fn sync(&self) -> PyResult<()> {
+                    match &self.$parent {
+                        Some(parent) => parent.write(self.$field.clone()),
+                        None => Ok(()),
+                    }
+                }
+            }
+        )?
+
+        #[pymethods]
+        impl $name {
+            fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+                crate::to_dict(py, &self.$field)
+            }
+
+            #[staticmethod]
+            fn from_dict(value: &PyAny) -> PyResult<$name> {
+                let $field = crate::from_dict::<$t>(value)?;
+                Ok($name { $field $(, $parent: None)? })
+            }
+
+            #[cfg(feature = "cbor")]
+            fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+                let state = serde_cbor::to_vec(&self.$field).map_err(crate::cbor_to_pyerr)?;
+                Ok(pyo3::types::PyBytes::new(py, &state).into())
+            }
+
+            #[cfg(feature = "cbor")]
+            fn __setstate__(&mut self, state: &[u8]) -> PyResult<()> {
+                self.$field = serde_cbor::from_slice(state).map_err(crate::cbor_to_pyerr)?;
+                $(
+                    if self.$parent.is_some() {
+                        self.sync()?;
+                    }
+                )?
+                Ok(())
+            }
+
+            #[cfg(feature = "cbor")]
+            fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (PyObject,))> {
+                let from_state = py.get_type::<$name>().getattr("_from_state")?;
+                Ok((from_state.into(), (self.__getstate__(py)?,)))
+            }
+
+            // A classmethod as pickle can find methods bound to a class, not
+            // static ones.
+            #[cfg(feature = "cbor")]
+            #[classmethod]
+            fn _from_state(_cls: &pyo3::types::PyType, state: &[u8]) -> PyResult<$name> {
+                let $field = serde_cbor::from_slice::<$t>(state).map_err(crate::cbor_to_pyerr)?;
+                Ok($name { $field $(, $parent: None)? })
+            }
+
+            // Copies are detached from the object this one was read from
+            fn __copy__(&self) -> $name {
+                $name {
+                    $field: self.$field.clone() $(, $parent: None)?
+                }
+            }
+
+            fn __deepcopy__(&self, _memo: &PyAny) -> $name {
+                self.__copy__()
+            }
+        }
+
+        #[pyproto]
+        impl pyo3::PyObjectProtocol for $name {
+            fn __str__(&self) -> PyResult<String> {
+                Ok(format!("{:?}", self.$field))
+            }
+
+            fn __repr__(&self) -> PyResult<String> {
+                self.__str__()
+            }
+
+            fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+                self.__str__()
+            }
+
+            fn __richcmp__(&self, other: $name, op: pyo3::basic::CompareOp) -> PyResult<PyObject> {
+                Ok(crate::im::richcmp(&self.$field, &other.$field, op))
+            }
+        }
+    };
+}
 
 pub mod fdu;
 pub use fdu::*;
@@ -57,3 +164,26 @@ sys.modules['fog05.im.net'] = net
 
     Ok(())
 }
+
+/// Name of a fieldless SDK enum variant, as found in descriptors.
+pub(crate) fn variant_name<T: Debug>(t: &T) -> String {
+    format!("{:?}", t)
+}
+
+/// Parses a fieldless SDK enum variant from its name, e.g. `"COLD"`.
+pub(crate) fn parse_variant<T: DeserializeOwned>(name: &str) -> PyResult<T> {
+    let de: StrDeserializer<ValueError> = name.into_deserializer();
+    T::deserialize(de).map_err(|err| PyErr::new::<exceptions::PyValueError, _>(err.to_string()))
+}
+
+/// `__eq__` and `__ne__` for the wrappers, ordering is not supported.
+///
+/// The wrappers stay unhashable: the SDK types do not implement `Hash`, and
+/// most wrappers can be changed through their properties.
+pub(crate) fn richcmp<T: PartialEq>(a: &T, b: &T, op: CompareOp) -> PyObject {
+    Python::with_gil(|py| match op {
+        CompareOp::Eq => (a == b).into_py(py),
+        CompareOp::Ne => (a != b).into_py(py),
+        _ => py.NotImplemented(),
+    })
+}
@@ -13,161 +13,160 @@
 
 use fog05_sdk::types;
 use pyo3::prelude::*;
-use pyo3::PyObjectProtocol;
 
-use crate::{json_to_pyerr, utf8_to_pyerr, yaml_to_pyerr};
+use crate::{from_json, from_yaml, json_to_pyerr, yaml_to_pyerr};
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VethKind {
     pub t: types::VETHKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VlanKind {
     pub t: types::VLANKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VxlanKind {
     pub t: types::VXLANKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct BridgeKind {
     pub t: types::BridgeKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct GreKind {
     pub t: types::GREKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct MacVlanKind {
     pub t: types::MACVLANKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VirtualInterfaceKind {
     pub t: types::VirtualInterfaceKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VirtualInterface {
     pub t: types::VirtualInterface,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VlanConfKind {
     pub t: types::VLANConfKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VxlanConfKind {
     pub t: types::VXLANConfKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VirtualInterfaceConfigKind {
     pub t: types::VirtualInterfaceConfigKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VirtualInterfaceConfig {
     pub t: types::VirtualInterfaceConfig,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct NetworkNamespace {
     pub t: types::NetworkNamespace,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct ConnectionPoint {
     pub t: types::ConnectionPoint,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct ConnectionPointConfig {
     pub t: types::ConnectionPointConfig,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct InterfaceKind {
     pub t: types::InterfaceKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct Interface {
     pub t: types::Interface,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct IpVersion {
     pub t: types::IPVersion,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct MCastVxlanInfo {
     pub t: types::MCastVXLANInfo,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct TreeGreInfo {
     pub t: types::TreeGREInfo,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct P2pVxlanInfo {
     pub t: types::P2PVXLANInfo,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct P2pGreInfo {
     pub t: types::P2PGREInfo,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct LinkKind {
     pub t: types::LinkKind,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct IpConfiguration {
     pub t: types::IPConfiguration,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VirtualNetwork {
     pub t: types::VirtualNetwork,
 }
 
-#[pyclass]
+#[pyclass(module = "fog05.im.net")]
 #[derive(Clone, Debug)]
 pub struct VirtualNetworkConfig {
     pub t: types::VirtualNetworkConfig,
@@ -215,10 +214,7 @@ impl VethKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VethKind> {
-        let t = serde_json::from_str::<types::VETHKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VETHKind>(raw_data)?;
         Ok(VethKind { t })
     }
 
@@ -230,28 +226,12 @@ impl VethKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VethKind> {
-        let t = serde_yaml::from_str::<types::VETHKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VETHKind>(raw_data)?;
         Ok(VethKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VethKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VethKind, t, types::VETHKind);
 
 #[pymethods]
 impl VlanKind {
@@ -265,10 +245,7 @@ impl VlanKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VlanKind> {
-        let t = serde_json::from_str::<types::VLANKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VLANKind>(raw_data)?;
         Ok(VlanKind { t })
     }
 
@@ -280,28 +257,12 @@ impl VlanKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VlanKind> {
-        let t = serde_yaml::from_str::<types::VLANKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VLANKind>(raw_data)?;
         Ok(VlanKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VlanKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VlanKind, t, types::VLANKind);
 
 #[pymethods]
 impl VxlanKind {
@@ -315,10 +276,7 @@ impl VxlanKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VxlanKind> {
-        let t = serde_json::from_str::<types::VXLANKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VXLANKind>(raw_data)?;
         Ok(VxlanKind { t })
     }
 
@@ -330,28 +288,12 @@ impl VxlanKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VxlanKind> {
-        let t = serde_yaml::from_str::<types::VXLANKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VXLANKind>(raw_data)?;
         Ok(VxlanKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VxlanKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VxlanKind, t, types::VXLANKind);
 
 #[pymethods]
 impl BridgeKind {
@@ -365,10 +307,7 @@ impl BridgeKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<BridgeKind> {
-        let t = serde_json::from_str::<types::BridgeKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::BridgeKind>(raw_data)?;
         Ok(BridgeKind { t })
     }
 
@@ -380,28 +319,12 @@ impl BridgeKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<BridgeKind> {
-        let t = serde_yaml::from_str::<types::BridgeKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::BridgeKind>(raw_data)?;
         Ok(BridgeKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for BridgeKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(BridgeKind, t, types::BridgeKind);
 
 #[pymethods]
 impl GreKind {
@@ -415,10 +338,7 @@ impl GreKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<GreKind> {
-        let t = serde_json::from_str::<types::GREKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::GREKind>(raw_data)?;
         Ok(GreKind { t })
     }
 
@@ -430,28 +350,12 @@ impl GreKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<GreKind> {
-        let t = serde_yaml::from_str::<types::GREKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::GREKind>(raw_data)?;
         Ok(GreKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for GreKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(GreKind, t, types::GREKind);
 
 #[pymethods]
 impl MacVlanKind {
@@ -465,10 +369,7 @@ impl MacVlanKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<MacVlanKind> {
-        let t = serde_json::from_str::<types::MACVLANKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::MACVLANKind>(raw_data)?;
         Ok(MacVlanKind { t })
     }
 
@@ -480,28 +381,12 @@ impl MacVlanKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<MacVlanKind> {
-        let t = serde_yaml::from_str::<types::MACVLANKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::MACVLANKind>(raw_data)?;
         Ok(MacVlanKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for MacVlanKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(MacVlanKind, t, types::MACVLANKind);
 
 #[pymethods]
 impl VirtualInterfaceKind {
@@ -515,10 +400,7 @@ impl VirtualInterfaceKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VirtualInterfaceKind> {
-        let t = serde_json::from_str::<types::VirtualInterfaceKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VirtualInterfaceKind>(raw_data)?;
         Ok(VirtualInterfaceKind { t })
     }
 
@@ -530,28 +412,12 @@ impl VirtualInterfaceKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VirtualInterfaceKind> {
-        let t = serde_yaml::from_str::<types::VirtualInterfaceKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VirtualInterfaceKind>(raw_data)?;
         Ok(VirtualInterfaceKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VirtualInterfaceKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VirtualInterfaceKind, t, types::VirtualInterfaceKind);
 
 #[pymethods]
 impl VirtualInterface {
@@ -565,10 +431,7 @@ impl VirtualInterface {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VirtualInterface> {
-        let t = serde_json::from_str::<types::VirtualInterface>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VirtualInterface>(raw_data)?;
         Ok(VirtualInterface { t })
     }
 
@@ -580,28 +443,12 @@ impl VirtualInterface {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VirtualInterface> {
-        let t = serde_yaml::from_str::<types::VirtualInterface>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VirtualInterface>(raw_data)?;
         Ok(VirtualInterface { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VirtualInterface {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VirtualInterface, t, types::VirtualInterface);
 
 #[pymethods]
 impl VlanConfKind {
@@ -615,10 +462,7 @@ impl VlanConfKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VlanConfKind> {
-        let t = serde_json::from_str::<types::VLANConfKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VLANConfKind>(raw_data)?;
         Ok(VlanConfKind { t })
     }
 
@@ -630,28 +474,12 @@ impl VlanConfKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VlanConfKind> {
-        let t = serde_yaml::from_str::<types::VLANConfKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VLANConfKind>(raw_data)?;
         Ok(VlanConfKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VlanConfKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VlanConfKind, t, types::VLANConfKind);
 
 #[pymethods]
 impl VxlanConfKind {
@@ -665,10 +493,7 @@ impl VxlanConfKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VxlanConfKind> {
-        let t = serde_json::from_str::<types::VXLANConfKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VXLANConfKind>(raw_data)?;
         Ok(VxlanConfKind { t })
     }
 
@@ -680,28 +505,12 @@ impl VxlanConfKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VxlanConfKind> {
-        let t = serde_yaml::from_str::<types::VXLANConfKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VXLANConfKind>(raw_data)?;
         Ok(VxlanConfKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VxlanConfKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VxlanConfKind, t, types::VXLANConfKind);
 
 #[pymethods]
 impl VirtualInterfaceConfigKind {
@@ -715,10 +524,7 @@ impl VirtualInterfaceConfigKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VirtualInterfaceConfigKind> {
-        let t = serde_json::from_str::<types::VirtualInterfaceConfigKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VirtualInterfaceConfigKind>(raw_data)?;
         Ok(VirtualInterfaceConfigKind { t })
     }
 
@@ -730,28 +536,16 @@ impl VirtualInterfaceConfigKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VirtualInterfaceConfigKind> {
-        let t = serde_yaml::from_str::<types::VirtualInterfaceConfigKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VirtualInterfaceConfigKind>(raw_data)?;
         Ok(VirtualInterfaceConfigKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VirtualInterfaceConfigKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(
+    VirtualInterfaceConfigKind,
+    t,
+    types::VirtualInterfaceConfigKind
+);
 
 #[pymethods]
 impl VirtualInterfaceConfig {
@@ -765,10 +559,7 @@ impl VirtualInterfaceConfig {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VirtualInterfaceConfig> {
-        let t = serde_json::from_str::<types::VirtualInterfaceConfig>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VirtualInterfaceConfig>(raw_data)?;
         Ok(VirtualInterfaceConfig { t })
     }
 
@@ -780,28 +571,12 @@ impl VirtualInterfaceConfig {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VirtualInterfaceConfig> {
-        let t = serde_yaml::from_str::<types::VirtualInterfaceConfig>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VirtualInterfaceConfig>(raw_data)?;
         Ok(VirtualInterfaceConfig { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VirtualInterfaceConfig {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VirtualInterfaceConfig, t, types::VirtualInterfaceConfig);
 
 #[pymethods]
 impl NetworkNamespace {
@@ -815,10 +590,7 @@ impl NetworkNamespace {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<NetworkNamespace> {
-        let t = serde_json::from_str::<types::NetworkNamespace>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::NetworkNamespace>(raw_data)?;
         Ok(NetworkNamespace { t })
     }
 
@@ -830,28 +602,12 @@ impl NetworkNamespace {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<NetworkNamespace> {
-        let t = serde_yaml::from_str::<types::NetworkNamespace>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::NetworkNamespace>(raw_data)?;
         Ok(NetworkNamespace { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for NetworkNamespace {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(NetworkNamespace, t, types::NetworkNamespace);
 
 #[pymethods]
 impl ConnectionPoint {
@@ -865,10 +621,7 @@ impl ConnectionPoint {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<ConnectionPoint> {
-        let t = serde_json::from_str::<types::ConnectionPoint>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::ConnectionPoint>(raw_data)?;
         Ok(ConnectionPoint { t })
     }
 
@@ -880,28 +633,12 @@ impl ConnectionPoint {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<ConnectionPoint> {
-        let t = serde_yaml::from_str::<types::ConnectionPoint>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::ConnectionPoint>(raw_data)?;
         Ok(ConnectionPoint { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for ConnectionPoint {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(ConnectionPoint, t, types::ConnectionPoint);
 
 #[pymethods]
 impl ConnectionPointConfig {
@@ -915,10 +652,7 @@ impl ConnectionPointConfig {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<ConnectionPointConfig> {
-        let t = serde_json::from_str::<types::ConnectionPointConfig>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::ConnectionPointConfig>(raw_data)?;
         Ok(ConnectionPointConfig { t })
     }
 
@@ -930,28 +664,12 @@ impl ConnectionPointConfig {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<ConnectionPointConfig> {
-        let t = serde_yaml::from_str::<types::ConnectionPointConfig>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::ConnectionPointConfig>(raw_data)?;
         Ok(ConnectionPointConfig { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for ConnectionPointConfig {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(ConnectionPointConfig, t, types::ConnectionPointConfig);
 
 #[pymethods]
 impl InterfaceKind {
@@ -965,10 +683,7 @@ impl InterfaceKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<InterfaceKind> {
-        let t = serde_json::from_str::<types::InterfaceKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::InterfaceKind>(raw_data)?;
         Ok(InterfaceKind { t })
     }
 
@@ -980,28 +695,12 @@ impl InterfaceKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<InterfaceKind> {
-        let t = serde_yaml::from_str::<types::InterfaceKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::InterfaceKind>(raw_data)?;
         Ok(InterfaceKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for InterfaceKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(InterfaceKind, t, types::InterfaceKind);
 
 #[pymethods]
 impl Interface {
@@ -1015,10 +714,7 @@ impl Interface {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<Interface> {
-        let t = serde_json::from_str::<types::Interface>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::Interface>(raw_data)?;
         Ok(Interface { t })
     }
 
@@ -1030,28 +726,12 @@ impl Interface {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<Interface> {
-        let t = serde_yaml::from_str::<types::Interface>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::Interface>(raw_data)?;
         Ok(Interface { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for Interface {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(Interface, t, types::Interface);
 
 #[pymethods]
 impl IpVersion {
@@ -1065,10 +745,7 @@ impl IpVersion {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<IpVersion> {
-        let t = serde_json::from_str::<types::IPVersion>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::IPVersion>(raw_data)?;
         Ok(IpVersion { t })
     }
 
@@ -1080,28 +757,12 @@ impl IpVersion {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<IpVersion> {
-        let t = serde_yaml::from_str::<types::IPVersion>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::IPVersion>(raw_data)?;
         Ok(IpVersion { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for IpVersion {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(IpVersion, t, types::IPVersion);
 
 #[pymethods]
 impl MCastVxlanInfo {
@@ -1115,10 +776,7 @@ impl MCastVxlanInfo {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<MCastVxlanInfo> {
-        let t = serde_json::from_str::<types::MCastVXLANInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::MCastVXLANInfo>(raw_data)?;
         Ok(MCastVxlanInfo { t })
     }
 
@@ -1130,28 +788,12 @@ impl MCastVxlanInfo {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<MCastVxlanInfo> {
-        let t = serde_yaml::from_str::<types::MCastVXLANInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::MCastVXLANInfo>(raw_data)?;
         Ok(MCastVxlanInfo { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for MCastVxlanInfo {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(MCastVxlanInfo, t, types::MCastVXLANInfo);
 
 #[pymethods]
 impl TreeGreInfo {
@@ -1165,10 +807,7 @@ impl TreeGreInfo {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<TreeGreInfo> {
-        let t = serde_json::from_str::<types::TreeGREInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::TreeGREInfo>(raw_data)?;
         Ok(TreeGreInfo { t })
     }
 
@@ -1180,28 +819,12 @@ impl TreeGreInfo {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<TreeGreInfo> {
-        let t = serde_yaml::from_str::<types::TreeGREInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::TreeGREInfo>(raw_data)?;
         Ok(TreeGreInfo { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for TreeGreInfo {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(TreeGreInfo, t, types::TreeGREInfo);
 
 #[pymethods]
 impl P2pVxlanInfo {
@@ -1215,10 +838,7 @@ impl P2pVxlanInfo {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<P2pVxlanInfo> {
-        let t = serde_json::from_str::<types::P2PVXLANInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::P2PVXLANInfo>(raw_data)?;
         Ok(P2pVxlanInfo { t })
     }
 
@@ -1230,28 +850,12 @@ impl P2pVxlanInfo {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<P2pVxlanInfo> {
-        let t = serde_yaml::from_str::<types::P2PVXLANInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::P2PVXLANInfo>(raw_data)?;
         Ok(P2pVxlanInfo { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for P2pVxlanInfo {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(P2pVxlanInfo, t, types::P2PVXLANInfo);
 
 #[pymethods]
 impl P2pGreInfo {
@@ -1265,10 +869,7 @@ impl P2pGreInfo {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<P2pGreInfo> {
-        let t = serde_json::from_str::<types::P2PGREInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::P2PGREInfo>(raw_data)?;
         Ok(P2pGreInfo { t })
     }
 
@@ -1280,28 +881,12 @@ impl P2pGreInfo {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<P2pGreInfo> {
-        let t = serde_yaml::from_str::<types::P2PGREInfo>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::P2PGREInfo>(raw_data)?;
         Ok(P2pGreInfo { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for P2pGreInfo {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(P2pGreInfo, t, types::P2PGREInfo);
 
 #[pymethods]
 impl LinkKind {
@@ -1315,10 +900,7 @@ impl LinkKind {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<LinkKind> {
-        let t = serde_json::from_str::<types::LinkKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::LinkKind>(raw_data)?;
         Ok(LinkKind { t })
     }
 
@@ -1330,28 +912,12 @@ impl LinkKind {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<LinkKind> {
-        let t = serde_yaml::from_str::<types::LinkKind>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::LinkKind>(raw_data)?;
         Ok(LinkKind { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for LinkKind {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(LinkKind, t, types::LinkKind);
 
 #[pymethods]
 impl IpConfiguration {
@@ -1365,10 +931,7 @@ impl IpConfiguration {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<IpConfiguration> {
-        let t = serde_json::from_str::<types::IPConfiguration>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::IPConfiguration>(raw_data)?;
         Ok(IpConfiguration { t })
     }
 
@@ -1380,28 +943,12 @@ impl IpConfiguration {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<IpConfiguration> {
-        let t = serde_yaml::from_str::<types::IPConfiguration>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::IPConfiguration>(raw_data)?;
         Ok(IpConfiguration { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for IpConfiguration {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(IpConfiguration, t, types::IPConfiguration);
 
 #[pymethods]
 impl VirtualNetwork {
@@ -1415,10 +962,7 @@ impl VirtualNetwork {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VirtualNetwork> {
-        let t = serde_json::from_str::<types::VirtualNetwork>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VirtualNetwork>(raw_data)?;
         Ok(VirtualNetwork { t })
     }
 
@@ -1430,28 +974,12 @@ impl VirtualNetwork {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VirtualNetwork> {
-        let t = serde_yaml::from_str::<types::VirtualNetwork>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VirtualNetwork>(raw_data)?;
         Ok(VirtualNetwork { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VirtualNetwork {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VirtualNetwork, t, types::VirtualNetwork);
 
 #[pymethods]
 impl VirtualNetworkConfig {
@@ -1465,10 +993,7 @@ impl VirtualNetworkConfig {
     #[cfg(feature = "json")]
     #[staticmethod]
     fn deserialize_json(raw_data: &[u8]) -> PyResult<VirtualNetworkConfig> {
-        let t = serde_json::from_str::<types::VirtualNetworkConfig>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(json_to_pyerr)?;
+        let t = from_json::<types::VirtualNetworkConfig>(raw_data)?;
         Ok(VirtualNetworkConfig { t })
     }
 
@@ -1480,25 +1005,9 @@ impl VirtualNetworkConfig {
     #[cfg(feature = "yaml")]
     #[staticmethod]
     fn deserialize_yaml(raw_data: &[u8]) -> PyResult<VirtualNetworkConfig> {
-        let t = serde_yaml::from_str::<types::VirtualNetworkConfig>(
-            std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?,
-        )
-        .map_err(yaml_to_pyerr)?;
+        let t = from_yaml::<types::VirtualNetworkConfig>(raw_data)?;
         Ok(VirtualNetworkConfig { t })
     }
 }
 
-#[pyproto]
-impl PyObjectProtocol for VirtualNetworkConfig {
-    fn __str__(&self) -> PyResult<String> {
-        Ok(format!("{:?}", self.t))
-    }
-
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
-
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
-    }
-}
+im_wrapper!(VirtualNetworkConfig, t, types::VirtualNetworkConfig);
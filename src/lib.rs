@@ -11,20 +11,39 @@
 *   ADLINK fog05 team, <fog05@adlink-labs.tech>
 *********************************************************************************/
 
-use async_std::sync::Arc;
+use async_std::prelude::*;
+use async_std::sync::{Arc, RwLock, Weak};
 use async_std::task;
+use log::{info, trace, warn};
 use pyo3::create_exception;
 use pyo3::prelude::*;
-use pyo3::PyObjectProtocol;
-use pyo3::{exceptions, wrap_pymodule};
+use pyo3::types::{PyTuple, PyType};
+use pyo3::{exceptions, wrap_pyfunction, wrap_pymodule};
+use pyo3::{PyObjectProtocol, PyTypeObject};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+use zenoh::net::config::{
+    ZN_LISTENER_STR, ZN_MODE_STR, ZN_PASSWORD_STR, ZN_PEER_STR, ZN_TLS_ROOT_CA_CERTIFICATE_STR,
+    ZN_USER_STR,
+};
+use zenoh::net::info::{ZN_INFO_PEER_PID_KEY, ZN_INFO_PID_KEY, ZN_INFO_ROUTER_PID_KEY};
+use zenoh::net::runtime::Runtime;
+use zenoh::net::{whatami, WhatAmI, ZInt};
 use zenoh::*;
 
+mod config;
+
 pub mod im;
 pub use im::*;
 
 pub mod api;
 pub use api::*;
 
+pub mod batch;
+pub use batch::*;
+
 pub mod plugins;
 pub use plugins::*;
 
@@ -86,51 +105,860 @@ sys.modules['fog05.zconnector'] = zconnector
     )?;
 
     m.add_class::<FosZenohSession>()?;
+    m.add_class::<Hello>()?;
+    m.add_class::<Batch>()?;
+    m.add_wrapped(wrap_pyfunction!(batch))?;
+    m.add("FError", py.get_type::<FError>())?;
+    // Defaults for the errors not coming from a fog05 FError
+    py.get_type::<FError>().setattr("kind", py.None())?;
+    py.get_type::<FError>().setattr("detail", py.None())?;
+    m.add("SessionClosed", py.get_type::<SessionClosed>())?;
+    m.add("SessionForked", py.get_type::<SessionForked>())?;
+    m.add("AuthenticationError", py.get_type::<AuthenticationError>())?;
+    m.add("RpcError", py.get_type::<RpcError>())?;
+    m.add("RequestTimeout", py.get_type::<RequestTimeout>())?;
+    m.add("NoServerFound", py.get_type::<NoServerFound>())?;
+    m.add("RpcDecodeError", py.get_type::<RpcDecodeError>())?;
+    m.add("NotFound", py.get_type::<NotFound>())?;
+    m.add("HypervisorError", py.get_type::<HypervisorError>())?;
+    m.add("Unimplemented", py.get_type::<Unimplemented>())?;
+    m.add("NetworkingError", py.get_type::<NetworkingError>())?;
+    m.add("SerializationError", py.get_type::<SerializationError>())?;
+    m.add(
+        "DescriptorParseError",
+        py.get_type::<DescriptorParseError>(),
+    )?;
 
     Ok(())
 }
 
-#[pyclass]
+const MONITOR_INTERVAL: Duration = Duration::from_secs(2);
+const RECONNECT_MIN_BACKOFF: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+const SIGNALS_INTERVAL: Duration = Duration::from_millis(100);
+
+static ASYNCIO_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Debug, Default)]
+pub struct SessionConfig {
+    pub mode: Option<String>,
+    pub peers: Vec<String>,
+    pub listeners: Vec<String>,
+    pub properties: HashMap<String, String>,
+    pub reconnect: bool,
+    pub request_timeout: Option<Duration>,
+    pub user: Option<String>,
+    pub password: Option<String>,
+    pub tls_root_ca_certificate: Option<String>,
+    pub tls_client_certificate: Option<String>,
+    pub tls_client_private_key: Option<String>,
+    pub system_id: Option<Uuid>,
+    pub tenant_id: Option<Uuid>,
+}
+
+impl SessionConfig {
+    pub fn validate(&self) -> PyResult<()> {
+        if let Some(mode) = &self.mode {
+            match mode.as_str() {
+                "client" | "peer" | "router" => (),
+                _ => {
+                    return Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                        "Invalid mode {}, expected one of client, peer or router",
+                        mode
+                    )))
+                }
+            }
+        }
+        if self.user.is_some() != self.password.is_some() {
+            return Err(PyErr::new::<exceptions::PyValueError, _>(
+                "Both user and password are needed for authentication",
+            ));
+        }
+        // zenoh only authenticates clients with user and password
+        if self.tls_client_certificate.is_some() || self.tls_client_private_key.is_some() {
+            return Err(PyErr::new::<exceptions::PyValueError, _>(
+                "TLS client certificates are not supported, use user and password",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Fails with an `OSError`, e.g. `FileNotFoundError`, naming the TLS
+    /// certificate that cannot be read, before zenoh reports it as a
+    /// generic open failure.
+    pub fn check_tls_files(&self) -> PyResult<()> {
+        if let Some(path) = &self.tls_root_ca_certificate {
+            std::fs::File::open(path).map_err(|err| {
+                // With an errno Python picks the matching OSError subclass
+                PyErr::new::<exceptions::PyOSError, _>((
+                    err.raw_os_error().unwrap_or(0),
+                    format!("Cannot read TLS root CA certificate: {}", err),
+                    path.clone(),
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    pub fn has_credentials(&self) -> bool {
+        self.user.is_some()
+    }
+
+    pub fn to_properties(&self) -> Properties {
+        let mut props = Properties::default();
+        for (k, v) in &self.properties {
+            props.insert(k.clone(), v.clone());
+        }
+        if let Some(mode) = &self.mode {
+            props.insert(ZN_MODE_STR.to_string(), mode.clone());
+        }
+        if !props.contains_key(ZN_MODE_STR) {
+            props.insert(ZN_MODE_STR.to_string(), "client".to_string());
+        }
+        if !self.peers.is_empty() {
+            props.insert(ZN_PEER_STR.to_string(), self.peers.join(","));
+        }
+        if !self.listeners.is_empty() {
+            props.insert(ZN_LISTENER_STR.to_string(), self.listeners.join(","));
+        }
+        let secrets = [
+            (ZN_USER_STR, &self.user),
+            (ZN_PASSWORD_STR, &self.password),
+            (
+                ZN_TLS_ROOT_CA_CERTIFICATE_STR,
+                &self.tls_root_ca_certificate,
+            ),
+        ];
+        for (key, value) in secrets.iter() {
+            if let Some(value) = value {
+                props.insert(key.to_string(), value.clone());
+            }
+        }
+        props
+    }
+}
+
 #[derive(Clone)]
-pub struct FosZenohSession {
+pub struct SessionHandles {
+    pub runtime: Runtime,
     pub zenoh: Arc<zenoh::Zenoh>,
     pub zsession: Arc<zenoh::net::Session>,
     pub zconnector: Arc<fog05_sdk::zconnector::ZConnector>,
 }
 
+#[derive(Default)]
+pub struct SessionCallbacks {
+    pub connected: Vec<PyObject>,
+    pub disconnected: Vec<PyObject>,
+    pub reconnected: Vec<PyObject>,
+}
+
+pub struct SessionState {
+    pub handles: RwLock<Option<SessionHandles>>,
+    pub connected: AtomicBool,
+    pub generation: AtomicUsize,
+    pub callbacks: RwLock<SessionCallbacks>,
+    /// Process that opened the session, the runtime threads do not survive a fork.
+    pub pid: u32,
+}
+
+#[pyclass(module = "fog05")]
+#[derive(Clone)]
+pub struct FosZenohSession {
+    pub config: SessionConfig,
+    state: Arc<SessionState>,
+}
+
 #[pymethods]
 impl FosZenohSession {
     #[new]
-    fn new(locator: String) -> PyResult<Self> {
+    #[args(
+        locator = "None",
+        mode = "None",
+        peers = "None",
+        listeners = "None",
+        properties = "None",
+        reconnect = "true",
+        request_timeout = "None",
+        user = "None",
+        password = "None",
+        tls_root_ca_certificate = "None",
+        tls_client_certificate = "None",
+        tls_client_private_key = "None",
+        system_id = "None",
+        tenant_id = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        locator: Option<String>,
+        mode: Option<String>,
+        peers: Option<Vec<String>>,
+        listeners: Option<Vec<String>>,
+        properties: Option<HashMap<String, String>>,
+        reconnect: bool,
+        request_timeout: Option<f64>,
+        user: Option<String>,
+        password: Option<String>,
+        tls_root_ca_certificate: Option<String>,
+        tls_client_certificate: Option<String>,
+        tls_client_private_key: Option<String>,
+        system_id: Option<String>,
+        tenant_id: Option<String>,
+    ) -> PyResult<Self> {
+        let mut all_peers: Vec<String> = locator.into_iter().collect();
+        all_peers.extend(peers.unwrap_or_default());
+        let config = SessionConfig {
+            mode,
+            peers: all_peers,
+            listeners: listeners.unwrap_or_default(),
+            properties: properties.unwrap_or_default(),
+            reconnect,
+            request_timeout: request_timeout.map(to_duration).transpose()?,
+            user,
+            password,
+            tls_root_ca_certificate,
+            tls_client_certificate,
+            tls_client_private_key,
+            system_id: system_id.as_deref().map(parse_uuid).transpose()?,
+            tenant_id: tenant_id.as_deref().map(parse_uuid).transpose()?,
+        };
+        config.validate()?;
+        block_on_without_gil(Self::open(config))
+    }
+
+    #[staticmethod]
+    fn from_config(path: &str) -> PyResult<Self> {
+        let config = config::from_file(path)?;
+        config.validate()?;
+        block_on_without_gil(Self::open(config))
+    }
+
+    #[staticmethod]
+    fn from_env() -> PyResult<Self> {
+        let config = config::from_env()?;
+        config.validate()?;
+        block_on_without_gil(Self::open(config))
+    }
+
+    #[staticmethod]
+    #[args(timeout = "1.0", what = "\"peer|router\"", properties = "None")]
+    fn scout(
+        timeout: f64,
+        what: &str,
+        properties: Option<HashMap<String, String>>,
+    ) -> PyResult<Vec<Hello>> {
+        let mut kinds: WhatAmI = 0;
+        for kind in what.split('|') {
+            kinds |= match kind.trim() {
+                "router" => whatami::ROUTER,
+                "peer" => whatami::PEER,
+                "client" => whatami::CLIENT,
+                _ => {
+                    return Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                        "Invalid whatami {}, expected router, peer or client",
+                        kind
+                    )))
+                }
+            }
+        }
+        let config = SessionConfig {
+            properties: properties.unwrap_or_default(),
+            ..Default::default()
+        };
+        let timeout = to_duration(timeout)?;
+        block_on_without_gil(Self::scout_for(kinds, &config, timeout))
+    }
+
+    #[staticmethod]
+    #[args(
+        timeout = "1.0",
+        mode = "None",
+        properties = "None",
+        reconnect = "true"
+    )]
+    fn discover(
+        timeout: f64,
+        mode: Option<String>,
+        properties: Option<HashMap<String, String>>,
+        reconnect: bool,
+    ) -> PyResult<Self> {
+        let config = SessionConfig {
+            mode,
+            properties: properties.unwrap_or_default(),
+            reconnect,
+            ..Default::default()
+        };
+        block_on_without_gil(async {
+            let mut hellos = Self::scout_for(
+                whatami::ROUTER | whatami::PEER,
+                &config,
+                to_duration(timeout)?,
+            )
+            .await?;
+            // Routers first, then peers, in the order they answered
+            hellos.sort_by_key(|hello| hello.whatami.as_deref() != Some("router"));
+            let hello = hellos
+                .into_iter()
+                .find(|hello| !hello.locators.is_empty())
+                .ok_or_else(|| {
+                    PyErr::new::<crate::FError, _>("No zenoh router or peer found while scouting")
+                })?;
+            Self::open(SessionConfig {
+                peers: hello.locators,
+                ..config
+            })
+            .await
+        })
+    }
+
+    #[getter]
+    fn mode(&self) -> String {
+        self.config
+            .to_properties()
+            .get("mode")
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    #[getter]
+    fn peers(&self) -> Vec<String> {
+        self.config.peers.clone()
+    }
+
+    #[getter]
+    fn listeners(&self) -> Vec<String> {
+        self.config.listeners.clone()
+    }
+
+    #[getter]
+    fn request_timeout(&self) -> Option<f64> {
+        self.config.request_timeout.map(|d| d.as_secs_f64())
+    }
+
+    #[getter]
+    fn system_id(&self) -> Option<String> {
+        self.config.system_id.map(|id| id.to_string())
+    }
+
+    #[getter]
+    fn tenant_id(&self) -> Option<String> {
+        self.config.tenant_id.map(|id| id.to_string())
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        task::block_on(async { self.current_handles().await.is_err() })
+    }
+
+    #[getter]
+    fn connected(&self) -> bool {
+        self.state.connected.load(Ordering::SeqCst)
+    }
+
+    #[getter]
+    fn zid(&self) -> PyResult<String> {
+        let handles = self.handles()?;
+        Ok(task::block_on(handles.zsession.info())
+            .get(&ZN_INFO_PID_KEY)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    #[getter]
+    fn router_zids(&self) -> PyResult<Vec<String>> {
+        let handles = self.handles()?;
+        Ok(task::block_on(Self::pids(
+            &handles.zsession,
+            ZN_INFO_ROUTER_PID_KEY,
+        )))
+    }
+
+    #[getter]
+    fn peer_zids(&self) -> PyResult<Vec<String>> {
+        let handles = self.handles()?;
+        Ok(task::block_on(Self::pids(
+            &handles.zsession,
+            ZN_INFO_PEER_PID_KEY,
+        )))
+    }
+
+    #[getter]
+    fn locators(&self) -> PyResult<Vec<String>> {
+        let handles = self.handles()?;
+        Ok(task::block_on(Self::remote_locators(&handles.runtime)))
+    }
+
+    #[getter]
+    fn locator(&self) -> PyResult<Option<String>> {
+        Ok(self.locators()?.into_iter().next())
+    }
+
+    /// The fog05 node UUID of the machine running this process, derived
+    /// like the local agent does, not the node of the router the session is
+    /// connected to. Ask the agent with `AgentClient.get_node_uuid` for
+    /// that one.
+    #[getter]
+    fn local_node_uuid(&self) -> Option<String> {
+        fog05_sdk::get_node_uuid().ok().map(|id| id.to_string())
+    }
+
+    fn on_connected(&self, py: Python, callback: PyObject) -> PyResult<()> {
         task::block_on(async {
-            let zenoh = Arc::new(
-                Zenoh::new(Properties::from(format!("mode=client;peer={}", locator)).into())
-                    .await
-                    .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?,
-            );
-            let zsession = Arc::new(
-                zenoh::net::open(Properties::from(format!("mode=client;peer={}", locator)).into())
+            self.state
+                .callbacks
+                .write()
+                .await
+                .connected
+                .push(callback.clone_ref(py))
+        });
+        // Already connected, let the callback know right away
+        if self.state.connected.load(Ordering::SeqCst) {
+            callback.call0(py)?;
+        }
+        Ok(())
+    }
+
+    fn on_disconnected(&self, callback: PyObject) {
+        task::block_on(async {
+            self.state
+                .callbacks
+                .write()
+                .await
+                .disconnected
+                .push(callback)
+        })
+    }
+
+    fn on_reconnected(&self, callback: PyObject) {
+        task::block_on(async {
+            self.state
+                .callbacks
+                .write()
+                .await
+                .reconnected
+                .push(callback)
+        })
+    }
+
+    fn close(&self) -> PyResult<()> {
+        block_on_without_gil(async {
+            if self.forked() {
+                self.abandon().await;
+                return Ok(());
+            }
+            let handles = self.state.handles.write().await.take();
+            self.state.connected.store(false, Ordering::SeqCst);
+            if let Some(handles) = handles {
+                // Both handles share the same runtime, closing it tears down the
+                // connection even if in-flight calls are still holding them.
+                drop(handles.zconnector);
+                drop(handles.zenoh);
+                drop(handles.zsession);
+                handles
+                    .runtime
+                    .close()
                     .await
-                    .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?,
-            );
-            let zconnector = Arc::new(fog05_sdk::zconnector::ZConnector::new(
-                zenoh.clone(),
-                None,
-                None,
-            ));
-            Ok(Self {
-                zenoh,
-                zsession,
-                zconnector,
+                    .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn __enter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        _exc_type: &PyAny,
+        _exc_value: &PyAny,
+        _traceback: &PyAny,
+    ) -> PyResult<bool> {
+        self.close()?;
+        Ok(false)
+    }
+
+    /// Pickles the session as its configuration, unpickling opens a new one.
+    ///
+    /// The credentials are left out: if the session has some, the unpickled
+    /// one reads them again like `from_env`, from `FOS_USER` and
+    /// `FOS_PASSWORD` or the `FOS_CONFIG` file.
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, PyObject)> {
+        let config = &self.config;
+        let args: Vec<PyObject> = vec![
+            config.mode.to_object(py),
+            config.peers.to_object(py),
+            config.listeners.to_object(py),
+            config.properties.to_object(py),
+            config.reconnect.to_object(py),
+            self.request_timeout().to_object(py),
+            config.tls_root_ca_certificate.to_object(py),
+            self.system_id().to_object(py),
+            self.tenant_id().to_object(py),
+            config.has_credentials().to_object(py),
+        ];
+        let unpickle = py.get_type::<Self>().getattr("_unpickle")?;
+        Ok((unpickle.to_object(py), PyTuple::new(py, args).to_object(py)))
+    }
+
+    #[classmethod]
+    #[allow(clippy::too_many_arguments)]
+    fn _unpickle(
+        _cls: &PyType,
+        mode: Option<String>,
+        peers: Vec<String>,
+        listeners: Vec<String>,
+        properties: HashMap<String, String>,
+        reconnect: bool,
+        request_timeout: Option<f64>,
+        tls_root_ca_certificate: Option<String>,
+        system_id: Option<String>,
+        tenant_id: Option<String>,
+        has_credentials: bool,
+    ) -> PyResult<Self> {
+        let mut config = SessionConfig {
+            mode,
+            peers,
+            listeners,
+            properties,
+            reconnect,
+            request_timeout: request_timeout.map(to_duration).transpose()?,
+            tls_root_ca_certificate,
+            system_id: system_id.as_deref().map(parse_uuid).transpose()?,
+            tenant_id: tenant_id.as_deref().map(parse_uuid).transpose()?,
+            ..Default::default()
+        };
+        if has_credentials {
+            let env = config::from_env()?;
+            if env.user.is_none() {
+                return Err(PyErr::new::<exceptions::PyValueError, _>(
+                    "The pickled session was authenticated, set FOS_USER and FOS_PASSWORD to unpickle it",
+                ));
+            }
+            config.user = env.user;
+            config.password = env.password;
+        }
+        config.validate()?;
+        block_on_without_gil(Self::open(config))
+    }
+}
+
+impl FosZenohSession {
+    pub async fn open(config: SessionConfig) -> PyResult<Self> {
+        config.check_tls_files()?;
+        let handles = Self::connect(&config)
+            .await
+            .map_err(|err| open_to_pyerr(&config, err))?;
+        let state = Arc::new(SessionState {
+            handles: RwLock::new(Some(handles)),
+            connected: AtomicBool::new(true),
+            generation: AtomicUsize::new(0),
+            callbacks: RwLock::new(SessionCallbacks::default()),
+            pid: std::process::id(),
+        });
+        // Without peers to connect to a peer or router has nothing to recover
+        let mode = config.to_properties().get(ZN_MODE_STR).cloned();
+        if config.reconnect && (!config.peers.is_empty() || mode.as_deref() == Some("client")) {
+            task::spawn(Self::monitor(config.clone(), Arc::downgrade(&state)));
+        }
+        Ok(Self { config, state })
+    }
+
+    async fn connect(config: &SessionConfig) -> ZResult<SessionHandles> {
+        // A single runtime, thus a single connection, backs both the
+        // zenoh and the zenoh-net handles.
+        let runtime = Runtime::new(0, config.to_properties().into(), None).await?;
+        let zenoh = Arc::new(Zenoh::init(runtime.clone()).await);
+        let zsession =
+            Arc::new(zenoh::net::Session::init(runtime.clone(), true, vec![], vec![]).await);
+        let zconnector = Arc::new(fog05_sdk::zconnector::ZConnector::new(
+            zenoh.clone(),
+            config.system_id,
+            config.tenant_id,
+        ));
+        Ok(SessionHandles {
+            runtime,
+            zenoh,
+            zsession,
+            zconnector,
+        })
+    }
+
+    async fn is_connected(zsession: &zenoh::net::Session) -> bool {
+        let info = zsession.info().await;
+        [ZN_INFO_ROUTER_PID_KEY, ZN_INFO_PEER_PID_KEY]
+            .iter()
+            .any(|key| info.get(key).map(|pids| !pids.is_empty()).unwrap_or(false))
+    }
+
+    async fn pids(zsession: &zenoh::net::Session, key: ZInt) -> Vec<String> {
+        zsession
+            .info()
+            .await
+            .get(&key)
+            .map(|pids| {
+                pids.split(',')
+                    .filter(|pid| !pid.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Locators of the remote ends of the links currently opened by the runtime.
+    async fn remote_locators(runtime: &Runtime) -> Vec<String> {
+        let manager = runtime.read().await.manager.clone();
+        let mut locators = vec![];
+        for session in manager.get_sessions().await {
+            if let Ok(links) = session.get_links().await {
+                locators.extend(links.iter().map(|link| link.get_dst().to_string()));
+            }
+        }
+        locators
+    }
+
+    async fn monitor(config: SessionConfig, state: Weak<SessionState>) {
+        loop {
+            task::sleep(MONITOR_INTERVAL).await;
+            let zsession = match state.upgrade() {
+                Some(state) => match &*state.handles.read().await {
+                    Some(handles) => handles.zsession.clone(),
+                    None => return,
+                },
+                None => return,
+            };
+            if Self::is_connected(&zsession).await {
+                continue;
+            }
+            drop(zsession);
+
+            warn!("FosZenohSession disconnected, trying to reconnect");
+            match state.upgrade() {
+                Some(state) => {
+                    state.connected.store(false, Ordering::SeqCst);
+                    let disconnected = state.callbacks.read().await.disconnected.clone();
+                    Self::notify(disconnected);
+                }
+                None => return,
+            }
+
+            let mut backoff = RECONNECT_MIN_BACKOFF;
+            loop {
+                task::sleep(backoff).await;
+                let handles = match Self::connect(&config).await {
+                    Ok(handles) => handles,
+                    Err(e) => {
+                        trace!("FosZenohSession reconnection failed: {}", e);
+                        backoff = std::cmp::min(backoff * 2, RECONNECT_MAX_BACKOFF);
+                        continue;
+                    }
+                };
+                let state = match state.upgrade() {
+                    Some(state) => state,
+                    None => {
+                        let _ = handles.runtime.close().await;
+                        return;
+                    }
+                };
+                let mut guard = state.handles.write().await;
+                match guard.replace(handles) {
+                    Some(old) => {
+                        drop(guard);
+                        let _ = old.runtime.close().await;
+                    }
+                    None => {
+                        // Closed while we were reconnecting
+                        if let Some(handles) = guard.take() {
+                            let _ = handles.runtime.close().await;
+                        }
+                        return;
+                    }
+                }
+                state.generation.fetch_add(1, Ordering::SeqCst);
+                state.connected.store(true, Ordering::SeqCst);
+                info!("FosZenohSession reconnected");
+                let callbacks = state.callbacks.read().await;
+                let mut reconnected = callbacks.reconnected.clone();
+                reconnected.extend(callbacks.connected.iter().cloned());
+                drop(callbacks);
+                Self::notify(reconnected);
+                break;
+            }
+        }
+    }
+
+    fn notify(callbacks: Vec<PyObject>) {
+        if callbacks.is_empty() {
+            return;
+        }
+        // Callbacks run on their own thread so that waiting for the GIL
+        // never stalls the monitoring loop.
+        task::spawn_blocking(move || {
+            Python::with_gil(|py| {
+                for cb in callbacks {
+                    if let Err(e) = cb.call0(py) {
+                        e.print(py);
+                    }
+                }
             })
+        });
+    }
+
+    async fn scout_for(
+        what: WhatAmI,
+        config: &SessionConfig,
+        timeout: Duration,
+    ) -> PyResult<Vec<Hello>> {
+        let mut stream = zenoh::net::scout(what, config.to_properties().into()).await;
+        let deadline = Instant::now() + timeout;
+        let mut hellos = vec![];
+        while let Ok(Some(hello)) = async_std::future::timeout(
+            deadline.saturating_duration_since(Instant::now()),
+            stream.next(),
+        )
+        .await
+        {
+            hellos.push(Hello {
+                pid: hello.pid.map(|pid| pid.to_string()),
+                whatami: hello.whatami.map(whatami::to_string),
+                locators: hello
+                    .locators
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|locator| locator.to_string())
+                    .collect(),
+            });
+        }
+        Ok(hellos)
+    }
+
+    pub fn handles(&self) -> PyResult<SessionHandles> {
+        task::block_on(self.current_handles())
+    }
+
+    pub async fn current_handles(&self) -> PyResult<SessionHandles> {
+        if self.forked() {
+            self.abandon().await;
+            return Err(PyErr::new::<crate::SessionForked, _>(
+                "FosZenohSession was inherited through fork, open a new session in the child \
+                 process or pass it to the worker to have it re-created from its configuration",
+            ));
+        }
+        match &*self.state.handles.read().await {
+            Some(handles) => Ok(handles.clone()),
+            None => Err(PyErr::new::<crate::SessionClosed, _>(
+                "FosZenohSession is closed",
+            )),
+        }
+    }
+
+    fn forked(&self) -> bool {
+        self.state.pid != std::process::id()
+    }
+
+    /// Forgets the handles inherited from the parent process: closing them
+    /// would tear down the connection the parent is still using.
+    async fn abandon(&self) {
+        if let Some(handles) = self.state.handles.write().await.take() {
+            std::mem::forget(handles);
+        }
+        self.state.connected.store(false, Ordering::SeqCst);
+    }
+
+    /// Blocks on `fut`, giving up after `timeout` seconds, or after the
+    /// session `request_timeout` when no timeout is given.
+    pub fn call<F, T>(&self, timeout: Option<f64>, fut: F) -> PyResult<T>
+    where
+        F: Future<Output = PyResult<T>> + Send,
+        T: Send,
+    {
+        let timeout = self.timeout_for(timeout)?;
+        block_on_without_gil(with_timeout(timeout, fut))
+    }
+
+    /// Same as `call` but returns an asyncio awaitable instead of blocking.
+    pub fn call_async<F, T>(&self, py: Python, timeout: Option<f64>, fut: F) -> PyResult<PyObject>
+    where
+        F: Future<Output = PyResult<T>> + Send + 'static,
+        T: IntoPy<PyObject> + Send + 'static,
+    {
+        init_asyncio(py)?;
+        let timeout = self.timeout_for(timeout)?;
+        pyo3_asyncio::async_std::into_coroutine(py, async move {
+            let res = with_timeout(timeout, fut).await?;
+            Ok(Python::with_gil(|py| res.into_py(py)))
         })
     }
+
+    fn timeout_for(&self, timeout: Option<f64>) -> PyResult<Option<Duration>> {
+        match timeout {
+            Some(secs) => Ok(Some(to_duration(secs)?)),
+            None => Ok(self.config.request_timeout),
+        }
+    }
+
+    /// Incremented each time the session is re-established after a disconnection.
+    pub fn generation(&self) -> usize {
+        self.state.generation.load(Ordering::SeqCst)
+    }
 }
 
 #[pyproto]
 impl PyObjectProtocol for FosZenohSession {
     fn __str__(&self) -> PyResult<String> {
-        Ok("FosZenohSession".to_string())
+        let handles = match task::block_on(self.current_handles()) {
+            Ok(handles) => handles,
+            Err(_) => return Ok("FosZenohSession(closed)".to_string()),
+        };
+        task::block_on(async {
+            let zid = handles
+                .zsession
+                .info()
+                .await
+                .get(&ZN_INFO_PID_KEY)
+                .cloned()
+                .unwrap_or_default();
+            Ok(format!(
+                "FosZenohSession(zid={}, mode={}, connected={}, locator={}, routers={:?}, peers={:?}, local_node_uuid={})",
+                zid,
+                self.mode(),
+                self.connected(),
+                Self::remote_locators(&handles.runtime)
+                    .await
+                    .first()
+                    .map(String::as_str)
+                    .unwrap_or("None"),
+                Self::pids(&handles.zsession, ZN_INFO_ROUTER_PID_KEY).await,
+                Self::pids(&handles.zsession, ZN_INFO_PEER_PID_KEY).await,
+                self.local_node_uuid().unwrap_or_else(|| "None".to_string()),
+            ))
+        })
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        self.__str__()
+    }
+
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        self.__str__()
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct Hello {
+    #[pyo3(get)]
+    pub pid: Option<String>,
+    #[pyo3(get)]
+    pub whatami: Option<String>,
+    #[pyo3(get)]
+    pub locators: Vec<String>,
+}
+
+#[pyproto]
+impl PyObjectProtocol for Hello {
+    fn __str__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self))
     }
 
     fn __repr__(&self) -> PyResult<String> {
@@ -143,31 +971,637 @@ impl PyObjectProtocol for FosZenohSession {
 }
 
 create_exception!(fog05, FError, exceptions::PyException);
+create_exception!(fog05, SessionClosed, FError);
+create_exception!(fog05, SessionForked, SessionClosed);
+create_exception!(fog05, AuthenticationError, FError);
+create_exception!(fog05, RpcError, FError);
+create_exception!(fog05, RequestTimeout, RpcError);
+create_exception!(fog05, NoServerFound, RpcError);
+create_exception!(fog05, RpcDecodeError, RpcError);
+create_exception!(fog05, NotFound, FError);
+create_exception!(fog05, HypervisorError, FError);
+create_exception!(fog05, Unimplemented, FError);
+create_exception!(fog05, NetworkingError, FError);
+create_exception!(fog05, SerializationError, FError);
+create_exception!(fog05, DescriptorParseError, SerializationError);
 
 fn to_pyerr(err: fog05_sdk::fresult::FError) -> PyErr {
-    PyErr::new::<FError, _>(err.to_string())
+    use fog05_sdk::fresult::FError as E;
+    let msg = err.to_string();
+    // No wildcard arm: a new upstream variant must be classified here
+    match err {
+        E::NotFound => new_error::<NotFound>(msg, "NotFound", None),
+        E::Unimplemented => new_error::<Unimplemented>(msg, "Unimplemented", None),
+        E::HypervisorError(detail) => {
+            new_error::<HypervisorError>(msg, "HypervisorError", Some(detail))
+        }
+        E::NetworkingError(detail) => {
+            new_error::<NetworkingError>(msg, "NetworkingError", Some(detail))
+        }
+        E::EncodingError(detail) => {
+            new_error::<SerializationError>(msg, "EncodingError", Some(detail))
+        }
+        E::MalformedDescriptor(detail) => {
+            new_error::<DescriptorParseError>(msg, "MalformedDescriptor", Some(detail))
+        }
+        E::NotConnected => new_error::<FError>(msg, "NotConnected", None),
+        E::AlreadyPresent => new_error::<FError>(msg, "AlreadyPresent", None),
+        E::TransitionNotAllowed => new_error::<FError>(msg, "TransitionNotAllowed", None),
+        E::WrongKind => new_error::<FError>(msg, "WrongKind", None),
+        E::ZConnectorError => new_error::<FError>(msg, "ZConnectorError", None),
+        E::ZenohError(detail) => new_error::<FError>(msg, "ZenohError", Some(detail)),
+        E::IOError(detail) => new_error::<FError>(msg, "IOError", Some(detail)),
+        E::UknownError(detail) => new_error::<FError>(msg, "UknownError", Some(detail)),
+    }
+}
+
+fn unimplemented_err(what: &str) -> PyErr {
+    new_error::<Unimplemented>(
+        format!("{} is not implemented yet", what),
+        "Unimplemented",
+        None,
+    )
+}
+
+/// Builds an exception of type `T` carrying the fog05 error `kind`, the
+/// name of the `FError` variant, and its `detail` as attributes.
+fn new_error<T: PyTypeObject>(msg: String, kind: &str, detail: Option<String>) -> PyErr {
+    Python::with_gil(|py| {
+        let value = PyErr::new::<T, _>(msg).to_object(py);
+        // Exception instances accept any attribute, this cannot fail
+        let _ = value.setattr(py, "kind", kind);
+        let _ = value.setattr(py, "detail", detail);
+        PyErr::from_instance(value.as_ref(py))
+    })
 }
 
 fn zrpc_to_pyerr(err: zrpc::zrpcresult::ZRPCError) -> PyErr {
-    PyErr::new::<FError, _>(err.to_string())
+    use zrpc::zrpcresult::ZRPCError as E;
+    let msg = err.to_string();
+    // No wildcard arm: a new upstream variant must be classified here
+    match err {
+        E::TimedOut => new_error::<RequestTimeout>(msg, "TimedOut", None),
+        E::NotFound => new_error::<NoServerFound>(msg, "NotFound", None),
+        E::UnavailableError => new_error::<NoServerFound>(msg, "UnavailableError", None),
+        E::SerializationError(detail) => {
+            new_error::<RpcDecodeError>(msg, "SerializationError", Some(detail))
+        }
+        E::DeserializationError(detail) => {
+            new_error::<RpcDecodeError>(msg, "DeserializationError", Some(detail))
+        }
+        E::MissingValue => new_error::<RpcDecodeError>(msg, "MissingValue", None),
+        E::IncompleteError => new_error::<RpcDecodeError>(msg, "IncompleteError", None),
+        E::ZenohError(detail) => new_error::<RpcError>(msg, "ZenohError", Some(detail)),
+        E::StateTransitionNotAllowed(detail) => {
+            new_error::<RpcError>(msg, "StateTransitionNotAllowed", Some(detail))
+        }
+        E::Error(detail) => new_error::<RpcError>(msg, "Error", Some(detail)),
+        E::NotMatchingVersion => new_error::<RpcError>(msg, "NotMatchingVersion", None),
+    }
+}
+
+/// How zenoh reports the router closing the open handshake, which is what
+/// its authenticators do when rejecting the credentials.
+const HANDSHAKE_CLOSED: &str = "Received a close message";
+
+fn open_to_pyerr(config: &SessionConfig, err: ZError) -> PyErr {
+    match err.get_kind() {
+        // zenoh has no error kind for authentication failures
+        ZErrorKind::InvalidMessage { descr }
+            if config.has_credentials() && descr.starts_with(HANDSHAKE_CLOSED) =>
+        {
+            PyErr::new::<AuthenticationError, _>(err.to_string())
+        }
+        _ => PyErr::new::<FError, _>(err.to_string()),
+    }
+}
+
+fn parse_uuid(id: &str) -> PyResult<Uuid> {
+    Uuid::parse_str(id).map_err(|err| PyErr::new::<exceptions::PyValueError, _>(err.to_string()))
+}
+
+/// Binds pyo3-asyncio to the running event loop, on the first async call
+/// rather than at import time when the loop used by `asyncio.run` does not
+/// exist yet.
+fn init_asyncio(py: Python) -> PyResult<()> {
+    // Holding the GIL, no other thread can be initializing at the same time
+    if !ASYNCIO_INITIALIZED.load(Ordering::Acquire) {
+        pyo3_asyncio::try_init(py)?;
+        ASYNCIO_INITIALIZED.store(true, Ordering::Release);
+    }
+    Ok(())
+}
+
+fn to_duration(secs: f64) -> PyResult<Duration> {
+    if secs.is_finite() && secs >= 0.0 {
+        Ok(Duration::from_secs_f64(secs))
+    } else {
+        Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+            "Invalid timeout {}, expected a positive number of seconds",
+            secs
+        )))
+    }
+}
+
+/// Blocks on `fut` with the GIL released, so that other Python threads
+/// keep running while waiting on the network.
+///
+/// Signals are checked while waiting: if a handler raises, as the default
+/// SIGINT one does with KeyboardInterrupt, `fut` is dropped, cancelling it,
+/// and the exception is propagated.
+fn block_on_without_gil<F, T>(fut: F) -> PyResult<T>
+where
+    F: Future<Output = PyResult<T>> + Send,
+    T: Send,
+{
+    Python::with_gil(|py| py.allow_threads(move || task::block_on(fut.race(check_signals()))))
+}
+
+async fn check_signals<T>() -> PyResult<T> {
+    loop {
+        task::sleep(SIGNALS_INTERVAL).await;
+        Python::with_gil(|py| py.check_signals())?;
+    }
+}
+
+async fn with_timeout<F, T>(timeout: Option<Duration>, fut: F) -> PyResult<T>
+where
+    F: Future<Output = PyResult<T>>,
+{
+    match timeout {
+        Some(timeout) => async_std::future::timeout(timeout, fut)
+            .await
+            .map_err(|_| {
+                new_error::<RequestTimeout>(
+                    format!("Request timed out after {:?}", timeout),
+                    "TimedOut",
+                    None,
+                )
+            })?,
+        None => fut.await,
+    }
 }
 
 #[cfg(feature = "cbor")]
 fn cbor_to_pyerr(err: serde_cbor::Error) -> PyErr {
-    PyErr::new::<FError, _>(err.to_string())
+    new_error::<SerializationError>(err.to_string(), "SerializationError", None)
 }
 
 #[cfg(feature = "json")]
 fn json_to_pyerr(err: serde_json::Error) -> PyErr {
-    PyErr::new::<FError, _>(err.to_string())
+    new_error::<SerializationError>(err.to_string(), "SerializationError", None)
 }
 
 #[cfg(feature = "yaml")]
 fn yaml_to_pyerr(err: serde_yaml::Error) -> PyErr {
-    PyErr::new::<FError, _>(err.to_string())
+    new_error::<SerializationError>(err.to_string(), "SerializationError", None)
+}
+
+#[cfg(feature = "json")]
+fn from_json<T: serde::de::DeserializeOwned>(raw_data: &[u8]) -> PyResult<T> {
+    let content = std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?;
+    let mut de = serde_json::Deserializer::from_str(content);
+    let value = serde_path_to_error::deserialize(&mut de).map_err(|err| {
+        let inner = err.inner();
+        parse_error(
+            "json",
+            Some(inner.line()),
+            Some(inner.column()),
+            err.path().to_string(),
+            inner.to_string(),
+        )
+    })?;
+    // Rejects trailing characters, as serde_json::from_str does
+    de.end().map_err(|err| {
+        parse_error(
+            "json",
+            Some(err.line()),
+            Some(err.column()),
+            ".".to_string(),
+            err.to_string(),
+        )
+    })?;
+    Ok(value)
+}
+
+#[cfg(feature = "yaml")]
+fn from_yaml<T: serde::de::DeserializeOwned>(raw_data: &[u8]) -> PyResult<T> {
+    let content = std::str::from_utf8(raw_data).map_err(utf8_to_pyerr)?;
+    let de = serde_yaml::Deserializer::from_str(content);
+    serde_path_to_error::deserialize(de).map_err(|err| {
+        let inner = err.inner();
+        let location = inner.location();
+        parse_error(
+            "yaml",
+            location.as_ref().map(|l| l.line()),
+            location.as_ref().map(|l| l.column()),
+            err.path().to_string(),
+            inner.to_string(),
+        )
+    })
+}
+
+/// Converts `value` into native Python dicts, lists, strings and numbers.
+fn to_dict<T: serde::Serialize>(py: Python, value: &T) -> PyResult<PyObject> {
+    pythonize::pythonize(py, value)
+        .map_err(|err| new_error::<SerializationError>(err.to_string(), "SerializationError", None))
+}
+
+fn from_dict<T: serde::de::DeserializeOwned>(obj: &PyAny) -> PyResult<T> {
+    let mut de = pythonize::Depythonizer::from_object(obj);
+    serde_path_to_error::deserialize(&mut de).map_err(|err| {
+        parse_error(
+            "dict",
+            None,
+            None,
+            err.path().to_string(),
+            err.inner().to_string(),
+        )
+    })
+}
+
+/// Builds a `DescriptorParseError` locating the failure by `line`, `column`
+/// and dotted field `path`.
+fn parse_error(
+    format: &str,
+    line: Option<usize>,
+    column: Option<usize>,
+    path: String,
+    detail: String,
+) -> PyErr {
+    let msg = match line {
+        Some(line) => format!(
+            "Invalid {} descriptor at {} (line {}, column {}): {}",
+            format,
+            path,
+            line,
+            column.unwrap_or_default(),
+            detail
+        ),
+        None => format!("Invalid {} descriptor at {}: {}", format, path, detail),
+    };
+    Python::with_gil(|py| {
+        let err = new_error::<DescriptorParseError>(msg, "SerializationError", Some(detail));
+        let value = err.to_object(py);
+        // Exception instances accept any attribute, this cannot fail
+        let _ = value.setattr(py, "format", format);
+        let _ = value.setattr(py, "line", line);
+        let _ = value.setattr(py, "column", column);
+        let _ = value.setattr(py, "path", path);
+        PyErr::from_instance(value.as_ref(py))
+    })
 }
 
 #[cfg(any(feature = "yaml", feature = "json"))]
 fn utf8_to_pyerr(err: std::str::Utf8Error) -> PyErr {
-    PyErr::new::<FError, _>(err.to_string())
+    new_error::<SerializationError>(err.to_string(), "SerializationError", None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SessionConfig {
+        SessionConfig {
+            peers: vec!["tcp/127.0.0.1:7447".to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn is_value_error(err: PyErr) -> bool {
+        Python::with_gil(|py| err.is_instance::<exceptions::PyValueError>(py))
+    }
+
+    #[test]
+    fn validate_accepts_known_modes() {
+        for mode in &["client", "peer", "router"] {
+            let config = SessionConfig {
+                mode: Some(mode.to_string()),
+                ..config()
+            };
+            assert!(config.validate().is_ok());
+        }
+        assert!(config().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_mode() {
+        let config = SessionConfig {
+            mode: Some("broker".to_string()),
+            ..config()
+        };
+        assert!(is_value_error(config.validate().unwrap_err()));
+    }
+
+    #[test]
+    fn validate_requires_user_and_password_together() {
+        let user_only = SessionConfig {
+            user: Some("fos".to_string()),
+            ..config()
+        };
+        assert!(is_value_error(user_only.validate().unwrap_err()));
+        let password_only = SessionConfig {
+            password: Some("secret".to_string()),
+            ..config()
+        };
+        assert!(is_value_error(password_only.validate().unwrap_err()));
+        let both = SessionConfig {
+            user: Some("fos".to_string()),
+            password: Some("secret".to_string()),
+            ..config()
+        };
+        assert!(both.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_tls_client_certificates() {
+        let certificate = SessionConfig {
+            tls_client_certificate: Some("client.pem".to_string()),
+            ..config()
+        };
+        assert!(is_value_error(certificate.validate().unwrap_err()));
+        let key = SessionConfig {
+            tls_client_private_key: Some("client.key".to_string()),
+            ..config()
+        };
+        assert!(is_value_error(key.validate().unwrap_err()));
+    }
+
+    #[test]
+    fn to_properties_defaults_to_client_mode() {
+        let props = config().to_properties();
+        assert_eq!(props.get(ZN_MODE_STR).map(String::as_str), Some("client"));
+        assert_eq!(
+            props.get(ZN_PEER_STR).map(String::as_str),
+            Some("tcp/127.0.0.1:7447")
+        );
+        assert!(!props.contains_key(ZN_LISTENER_STR));
+        assert!(!props.contains_key(ZN_USER_STR));
+        assert!(!props.contains_key(ZN_PASSWORD_STR));
+    }
+
+    #[test]
+    fn to_properties_joins_endpoints_and_sets_credentials() {
+        let config = SessionConfig {
+            mode: Some("peer".to_string()),
+            peers: vec![
+                "tcp/10.0.0.1:7447".to_string(),
+                "tcp/10.0.0.2:7447".to_string(),
+            ],
+            listeners: vec!["tcp/0.0.0.0:7447".to_string()],
+            user: Some("fos".to_string()),
+            password: Some("secret".to_string()),
+            tls_root_ca_certificate: Some("ca.pem".to_string()),
+            ..Default::default()
+        };
+        let props = config.to_properties();
+        assert_eq!(props.get(ZN_MODE_STR).map(String::as_str), Some("peer"));
+        assert_eq!(
+            props.get(ZN_PEER_STR).map(String::as_str),
+            Some("tcp/10.0.0.1:7447,tcp/10.0.0.2:7447")
+        );
+        assert_eq!(
+            props.get(ZN_LISTENER_STR).map(String::as_str),
+            Some("tcp/0.0.0.0:7447")
+        );
+        assert_eq!(props.get(ZN_USER_STR).map(String::as_str), Some("fos"));
+        assert_eq!(
+            props.get(ZN_PASSWORD_STR).map(String::as_str),
+            Some("secret")
+        );
+        assert_eq!(
+            props
+                .get(ZN_TLS_ROOT_CA_CERTIFICATE_STR)
+                .map(String::as_str),
+            Some("ca.pem")
+        );
+    }
+
+    #[test]
+    fn to_properties_mode_overrides_raw_properties() {
+        let mut properties = HashMap::new();
+        properties.insert(ZN_MODE_STR.to_string(), "router".to_string());
+        properties.insert("local_routing".to_string(), "false".to_string());
+        let raw = SessionConfig {
+            properties: properties.clone(),
+            ..config()
+        };
+        let props = raw.to_properties();
+        assert_eq!(props.get(ZN_MODE_STR).map(String::as_str), Some("router"));
+        assert_eq!(
+            props.get("local_routing").map(String::as_str),
+            Some("false")
+        );
+
+        let explicit = SessionConfig {
+            mode: Some("peer".to_string()),
+            properties,
+            ..config()
+        };
+        let props = explicit.to_properties();
+        assert_eq!(props.get(ZN_MODE_STR).map(String::as_str), Some("peer"));
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Outer {
+        inner: Inner,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Inner {
+        value: u32,
+    }
+
+    fn attr<T: for<'p> FromPyObject<'p>>(err: &PyErr, name: &str) -> T {
+        Python::with_gil(|py| {
+            err.instance(py)
+                .getattr(name)
+                .and_then(|value| value.extract())
+                .unwrap()
+        })
+    }
+
+    fn is_parse_error(err: &PyErr) -> bool {
+        Python::with_gil(|py| {
+            err.is_instance::<DescriptorParseError>(py) && err.is_instance::<SerializationError>(py)
+        })
+    }
+
+    #[test]
+    fn parse_error_carries_location() {
+        let err = parse_error(
+            "yaml",
+            Some(3),
+            Some(7),
+            "inner.value".to_string(),
+            "invalid type".to_string(),
+        );
+        assert!(is_parse_error(&err));
+        assert_eq!(attr::<String>(&err, "format"), "yaml");
+        assert_eq!(attr::<Option<usize>>(&err, "line"), Some(3));
+        assert_eq!(attr::<Option<usize>>(&err, "column"), Some(7));
+        assert_eq!(attr::<String>(&err, "path"), "inner.value");
+        assert_eq!(attr::<String>(&err, "kind"), "SerializationError");
+        assert_eq!(
+            attr::<Option<String>>(&err, "detail").as_deref(),
+            Some("invalid type")
+        );
+        let msg = Python::with_gil(|py| err.instance(py).to_string());
+        assert_eq!(
+            msg,
+            "Invalid yaml descriptor at inner.value (line 3, column 7): invalid type"
+        );
+    }
+
+    #[test]
+    fn parse_error_without_location() {
+        let err = parse_error(
+            "dict",
+            None,
+            None,
+            "inner".to_string(),
+            "missing field".to_string(),
+        );
+        assert!(is_parse_error(&err));
+        assert_eq!(attr::<Option<usize>>(&err, "line"), None);
+        assert_eq!(attr::<Option<usize>>(&err, "column"), None);
+        let msg = Python::with_gil(|py| err.instance(py).to_string());
+        assert_eq!(msg, "Invalid dict descriptor at inner: missing field");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json_locates_errors() {
+        let raw = b"{\n  \"inner\": {\n    \"value\": true\n  }\n}";
+        let err = from_json::<Outer>(raw).unwrap_err();
+        assert!(is_parse_error(&err));
+        assert_eq!(attr::<String>(&err, "format"), "json");
+        assert_eq!(attr::<String>(&err, "path"), "inner.value");
+        assert_eq!(attr::<Option<usize>>(&err, "line"), Some(3));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json_rejects_trailing_characters() {
+        let outer = from_json::<Outer>(b"{\"inner\": {\"value\": 1}}\n").unwrap();
+        assert_eq!(outer.inner.value, 1);
+        let err = from_json::<Outer>(b"{\"inner\": {\"value\": 1}} x").unwrap_err();
+        assert!(is_parse_error(&err));
+        assert_eq!(attr::<String>(&err, "path"), ".");
+        assert_eq!(attr::<Option<usize>>(&err, "line"), Some(1));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml_locates_errors() {
+        let err = from_yaml::<Outer>(b"inner:\n  value: nope\n").unwrap_err();
+        assert!(is_parse_error(&err));
+        assert_eq!(attr::<String>(&err, "format"), "yaml");
+        assert_eq!(attr::<String>(&err, "path"), "inner.value");
+        assert!(attr::<Option<usize>>(&err, "line").is_some());
+    }
+
+    fn kind(err: &PyErr) -> (String, Option<String>) {
+        (attr(err, "kind"), attr(err, "detail"))
+    }
+
+    #[test]
+    fn fog05_errors_map_to_subclasses() {
+        use fog05_sdk::fresult::FError as E;
+        let err = to_pyerr(E::NotFound);
+        assert!(Python::with_gil(|py| err.is_instance::<NotFound>(py)));
+        assert_eq!(kind(&err), ("NotFound".to_string(), None));
+
+        let err = to_pyerr(E::HypervisorError("no such domain".to_string()));
+        assert!(Python::with_gil(|py| err.is_instance::<HypervisorError>(py)));
+        assert_eq!(
+            kind(&err),
+            (
+                "HypervisorError".to_string(),
+                Some("no such domain".to_string())
+            )
+        );
+
+        let err = to_pyerr(E::MalformedDescriptor("missing id".to_string()));
+        assert!(Python::with_gil(
+            |py| err.is_instance::<DescriptorParseError>(py)
+        ));
+        assert_eq!(kind(&err).0, "MalformedDescriptor");
+
+        let err = to_pyerr(E::EncodingError("truncated".to_string()));
+        assert!(Python::with_gil(|py| err
+            .is_instance::<SerializationError>(py)
+            && !err.is_instance::<DescriptorParseError>(py)));
+        assert_eq!(kind(&err).0, "EncodingError");
+
+        let err = to_pyerr(E::WrongKind);
+        assert!(Python::with_gil(|py| err.is_instance::<FError>(py)));
+        assert_eq!(kind(&err), ("WrongKind".to_string(), None));
+    }
+
+    #[test]
+    fn rpc_errors_map_to_subclasses() {
+        use zrpc::zrpcresult::ZRPCError as E;
+        let err = zrpc_to_pyerr(E::TimedOut);
+        assert!(Python::with_gil(|py| err.is_instance::<RequestTimeout>(py)
+            && err.is_instance::<RpcError>(py)
+            && err.is_instance::<FError>(py)));
+        assert_eq!(kind(&err), ("TimedOut".to_string(), None));
+
+        let err = zrpc_to_pyerr(E::UnavailableError);
+        assert!(Python::with_gil(|py| err.is_instance::<NoServerFound>(py)));
+        assert_eq!(kind(&err).0, "UnavailableError");
+
+        let err = zrpc_to_pyerr(E::DeserializationError("bad cbor".to_string()));
+        assert!(Python::with_gil(|py| err.is_instance::<RpcDecodeError>(py)));
+        assert_eq!(
+            kind(&err),
+            (
+                "DeserializationError".to_string(),
+                Some("bad cbor".to_string())
+            )
+        );
+
+        let err = zrpc_to_pyerr(E::NotMatchingVersion);
+        assert!(Python::with_gil(
+            |py| err.is_instance::<RpcError>(py) && !err.is_instance::<RpcDecodeError>(py)
+        ));
+        assert_eq!(kind(&err).0, "NotMatchingVersion");
+    }
+
+    fn invalid_message(descr: &str) -> ZError {
+        ZError::new(
+            ZErrorKind::InvalidMessage {
+                descr: descr.to_string(),
+            },
+            file!(),
+            line!(),
+            None,
+        )
+    }
+
+    #[test]
+    fn closed_handshake_is_an_authentication_error_with_credentials() {
+        let credentials = SessionConfig {
+            user: Some("fos".to_string()),
+            password: Some("secret".to_string()),
+            ..config()
+        };
+        let err = open_to_pyerr(&credentials, invalid_message(HANDSHAKE_CLOSED));
+        assert!(Python::with_gil(
+            |py| err.is_instance::<AuthenticationError>(py)
+        ));
+
+        let err = open_to_pyerr(&config(), invalid_message(HANDSHAKE_CLOSED));
+        assert!(Python::with_gil(
+            |py| err.is_instance::<FError>(py) && !err.is_instance::<AuthenticationError>(py)
+        ));
+
+        let err = open_to_pyerr(&credentials, invalid_message("Unexpected message"));
+        assert!(Python::with_gil(
+            |py| !err.is_instance::<AuthenticationError>(py)
+        ));
+    }
 }
@@ -11,17 +11,19 @@
 *   ADLINK fog05 team, <fog05@adlink-labs.tech>
 *********************************************************************************/
 
+use async_std::channel::TrySendError;
 use async_std::prelude::*;
 use async_std::sync::{Arc, RwLock};
 use async_std::task;
 use fog05_sdk::agent::{AgentPluginInterfaceClient as rustAgentClient, OSClient as rustOSclient};
 use fog05_sdk::im::*;
 use fog05_sdk::plugins::{HypervisorPlugin, NetworkingPluginClient as rustNetworkingClient};
-use log::{info, trace};
+use log::{error, info, trace};
 use pyo3::prelude::*;
 use pyo3::types::PyTuple;
 use pyo3::PyObject;
 use pyo3::PyObjectProtocol;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use uuid::Uuid;
 
@@ -36,24 +38,54 @@ use crate::im::net::{
 };
 
 use crate::FosZenohSession;
-use crate::{to_pyerr, zrpc_to_pyerr};
+use crate::{block_on_without_gil, parse_uuid, to_pyerr, zrpc_to_pyerr};
 
 #[pyclass]
 #[derive(Clone)]
 pub struct OsClient {
-    pub os: Arc<rustOSclient>,
+    pub z: FosZenohSession,
+    pub server_uuid: Uuid,
 }
 
 #[pyclass]
 #[derive(Clone)]
 pub struct AgentClient {
-    pub agent: Arc<rustAgentClient>,
+    pub z: FosZenohSession,
+    pub server_uuid: Uuid,
 }
 
 #[pyclass]
 #[derive(Clone)]
 pub struct NetClient {
-    pub net: Arc<rustNetworkingClient>,
+    pub z: FosZenohSession,
+    pub server_uuid: Uuid,
+}
+
+/// Async version of `OsClient`, bound to a single event loop like
+/// `fog05.api.AsyncFduApi`.
+#[pyclass]
+#[derive(Clone)]
+pub struct AsyncOsClient {
+    pub z: FosZenohSession,
+    pub server_uuid: Uuid,
+}
+
+/// Async version of `AgentClient`, bound to a single event loop like
+/// `fog05.api.AsyncFduApi`.
+#[pyclass]
+#[derive(Clone)]
+pub struct AsyncAgentClient {
+    pub z: FosZenohSession,
+    pub server_uuid: Uuid,
+}
+
+/// Async version of `NetClient`, bound to a single event loop like
+/// `fog05.api.AsyncFduApi`.
+#[pyclass]
+#[derive(Clone)]
+pub struct AsyncNetClient {
+    pub z: FosZenohSession,
+    pub server_uuid: Uuid,
 }
 
 #[pymodule]
@@ -61,61 +93,208 @@ pub fn plugins(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<OsClient>()?;
     m.add_class::<NetClient>()?;
     m.add_class::<AgentClient>()?;
+    m.add_class::<AsyncOsClient>()?;
+    m.add_class::<AsyncNetClient>()?;
+    m.add_class::<AsyncAgentClient>()?;
     m.add_class::<WrappedHypervisor>()?;
 
     Ok(())
 }
 
-#[pymethods]
-impl AgentClient {
-    #[new]
-    fn new(zenoh: FosZenohSession, server_uuid: String) -> PyResult<Self> {
-        let server_uuid = Uuid::parse_str(&server_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let agent = Arc::new(rustAgentClient::new(zenoh.zsession.clone(), server_uuid));
-            Ok(Self { agent })
-        })
-    }
+/// Implements `$client` and its async version `$async_client` from one list
+/// of methods, each calling a `$rpc_client` RPC.
+///
+/// A method runs its `let` statements, which check the arguments, binds the
+/// RPC client to `$rpc` and runs its async block: `$client` blocks on it,
+/// `$async_client` returns an awaitable. Both take an optional `timeout` in
+/// seconds after the listed arguments. The `unimplemented` methods only
+/// check their arguments and raise `Unimplemented`.
+macro_rules! rpc_clients {
+    (
+        $client:ident, $async_client:ident, $rpc_client:ident, $rpc:ident;
+        $(
+            fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)?) -> $ret:ty {
+                $(let $var:ident = $check:expr;)*
+                async $body:block
+            }
+        )*
+        unimplemented {
+            $(
+                fn $unimpl:ident($($uarg:ident: $uarg_ty:ty),* $(,)?) -> $uret:ty {
+                    $(let $uvar:ident = $ucheck:expr;)*
+                }
+            )*
+        }
+    ) => {
+        #[pymethods]
+        impl $client {
+            #[new]
+            fn new(zenoh: FosZenohSession, server_uuid: String) -> PyResult<Self> {
+                let server_uuid = parse_uuid(&server_uuid)?;
+                zenoh.handles()?;
+                Ok(Self {
+                    z: zenoh,
+                    server_uuid,
+                })
+            }
 
-    #[staticmethod]
-    fn find_servers(zenoh: FosZenohSession) -> PyResult<Vec<String>> {
-        task::block_on(async {
-            let res = rustAgentClient::find_servers(zenoh.zsession.clone())
-                .await
-                .map_err(zrpc_to_pyerr)?;
-            let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
-            Ok(servers)
-        })
-    }
+            #[staticmethod]
+            #[args(timeout = "None")]
+            fn find_servers(zenoh: FosZenohSession, timeout: Option<f64>) -> PyResult<Vec<String>> {
+                let zsession = zenoh.handles()?.zsession;
+                zenoh.call(timeout, async {
+                    let res = $rpc_client::find_servers(zsession)
+                        .await
+                        .map_err(zrpc_to_pyerr)?;
+                    let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
+                    Ok(servers)
+                })
+            }
 
-    #[staticmethod]
-    fn find_local_servers(zenoh: FosZenohSession) -> PyResult<Vec<String>> {
-        task::block_on(async {
-            let res = rustAgentClient::find_local_servers(zenoh.zsession.clone())
-                .await
-                .map_err(zrpc_to_pyerr)?;
-            let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
-            Ok(servers)
-        })
-    }
+            #[staticmethod]
+            #[args(timeout = "None")]
+            fn find_local_servers(
+                zenoh: FosZenohSession,
+                timeout: Option<f64>,
+            ) -> PyResult<Vec<String>> {
+                let zsession = zenoh.handles()?.zsession;
+                zenoh.call(timeout, async {
+                    let res = $rpc_client::find_local_servers(zsession)
+                        .await
+                        .map_err(zrpc_to_pyerr)?;
+                    let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
+                    Ok(servers)
+                })
+            }
+
+            $(
+                #[args(timeout = "None")]
+                fn $name(&self, $($arg: $arg_ty,)* timeout: Option<f64>) -> PyResult<$ret> {
+                    $(let $var = $check;)*
+                    let $rpc = self.rpc()?;
+                    self.z.call(timeout, async move $body)
+                }
+            )*
+
+            $(
+                fn $unimpl(&self, $($uarg: $uarg_ty),*) -> PyResult<$uret> {
+                    $(let $uvar = $ucheck;)*
+                    Err(crate::unimplemented_err(concat!(
+                        stringify!($client),
+                        ".",
+                        stringify!($unimpl)
+                    )))
+                }
+            )*
+        }
+
+        impl $client {
+            fn rpc(&self) -> PyResult<$rpc_client> {
+                let zsession = self.z.handles()?.zsession;
+                Ok($rpc_client::new(zsession, self.server_uuid))
+            }
+        }
+
+        #[pymethods]
+        impl $async_client {
+            #[new]
+            fn new(zenoh: FosZenohSession, server_uuid: String) -> PyResult<Self> {
+                let server_uuid = parse_uuid(&server_uuid)?;
+                zenoh.handles()?;
+                Ok(Self {
+                    z: zenoh,
+                    server_uuid,
+                })
+            }
+
+            #[staticmethod]
+            #[args(timeout = "None")]
+            fn find_servers(
+                py: Python,
+                zenoh: FosZenohSession,
+                timeout: Option<f64>,
+            ) -> PyResult<PyObject> {
+                let zsession = zenoh.handles()?.zsession;
+                zenoh.call_async(py, timeout, async move {
+                    let res = $rpc_client::find_servers(zsession)
+                        .await
+                        .map_err(zrpc_to_pyerr)?;
+                    let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
+                    Ok(servers)
+                })
+            }
+
+            #[staticmethod]
+            #[args(timeout = "None")]
+            fn find_local_servers(
+                py: Python,
+                zenoh: FosZenohSession,
+                timeout: Option<f64>,
+            ) -> PyResult<PyObject> {
+                let zsession = zenoh.handles()?.zsession;
+                zenoh.call_async(py, timeout, async move {
+                    let res = $rpc_client::find_local_servers(zsession)
+                        .await
+                        .map_err(zrpc_to_pyerr)?;
+                    let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
+                    Ok(servers)
+                })
+            }
+
+            $(
+                #[args(timeout = "None")]
+                fn $name(
+                    &self,
+                    py: Python,
+                    $($arg: $arg_ty,)*
+                    timeout: Option<f64>,
+                ) -> PyResult<PyObject> {
+                    $(let $var = $check;)*
+                    let $rpc = self.rpc()?;
+                    self.z.call_async(py, timeout, async move $body)
+                }
+            )*
+
+            $(
+                fn $unimpl(&self, $($uarg: $uarg_ty),*) -> PyResult<$uret> {
+                    $(let $uvar = $ucheck;)*
+                    Err(crate::unimplemented_err(concat!(
+                        stringify!($async_client),
+                        ".",
+                        stringify!($unimpl)
+                    )))
+                }
+            )*
+        }
+
+        impl $async_client {
+            fn rpc(&self) -> PyResult<$rpc_client> {
+                let zsession = self.z.handles()?.zsession;
+                Ok($rpc_client::new(zsession, self.server_uuid))
+            }
+        }
+    };
+}
 
-    fn verify_server(&self) -> PyResult<bool> {
-        task::block_on(async { self.agent.verify_server().await.map_err(zrpc_to_pyerr) })
+rpc_clients! {
+    AgentClient, AsyncAgentClient, rustAgentClient, agent;
+
+    fn verify_server() -> bool {
+        async {
+            agent.verify_server().await.map_err(zrpc_to_pyerr)
+        }
     }
 
-    fn fdu_info(&self, fdu_uuid: String) -> PyResult<crate::im::fdu::FduDescriptor> {
-        let fdu_uuid = Uuid::parse_str(&fdu_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let d = self
-                .agent
+    fn fdu_info(fdu_uuid: String) -> crate::im::fdu::FduDescriptor {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        async {
+            let d = agent
                 .fdu_info(fdu_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduDescriptor { d })
-        })
+        }
     }
 
     // fn image_info(&self, image_uuid : String) -> PyResult<crate::im::fdu::Image> {
@@ -131,965 +310,787 @@ impl AgentClient {
     // }
 
     fn node_fdu_info(
-        &self,
         fdu_uuid: String,
         node_uuid: String,
         instance_uuid: String,
-    ) -> PyResult<crate::im::fdu::FduRecord> {
-        let fdu_uuid = Uuid::parse_str(&fdu_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let node_uuid = Uuid::parse_str(&node_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let r = self
-                .agent
+    ) -> crate::im::fdu::FduRecord {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        let node_uuid = parse_uuid(&node_uuid)?;
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        async {
+            let r = agent
                 .node_fdu_info(fdu_uuid, node_uuid, instance_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(crate::im::fdu::FduRecord { r })
-        })
+        }
     }
 
-    fn network_info(&self, network_uuid: String) -> PyResult<VirtualNetwork> {
-        let network_uuid = Uuid::parse_str(&network_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn network_info(network_uuid: String) -> VirtualNetwork {
+        let network_uuid = parse_uuid(&network_uuid)?;
+        async {
+            let t = agent
                 .network_info(network_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualNetwork { t })
-        })
+        }
     }
 
-    fn connection_point_info(&self, cp_uuid: String) -> PyResult<ConnectionPoint> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn connection_point_info(cp_uuid: String) -> ConnectionPoint {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        async {
+            let t = agent
                 .connection_point_info(cp_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(ConnectionPoint { t })
-        })
+        }
     }
 
-    fn node_management_address(&self, node_uuid: String) -> PyResult<String> {
-        let node_uuid = Uuid::parse_str(&node_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn node_management_address(node_uuid: String) -> String {
+        let node_uuid = parse_uuid(&node_uuid)?;
+        async {
+            let t = agent
                 .node_management_address(node_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
-    }
-
-    fn create_virtual_network(&self, _vnet: VirtualNetworkConfig) -> PyResult<VirtualNetwork> {
-        unimplemented!()
-    }
-
-    fn remove_virtual_network(&self, _vnet_uuid: String) -> PyResult<String> {
-        unimplemented!()
+        }
     }
 
-    fn create_connection_point(&self, cp: ConnectionPointConfig) -> PyResult<ConnectionPoint> {
-        task::block_on(async {
-            let t = self
-                .agent
+    fn create_connection_point(cp: ConnectionPointConfig) -> ConnectionPoint {
+        async {
+            let t = agent
                 .create_connection_point(cp.t)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(ConnectionPoint { t })
-        })
+        }
     }
 
-    fn remove_connection_point(&self, cp_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn remove_connection_point(cp_uuid: String) -> String {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        async {
+            let t = agent
                 .remove_connection_point(cp_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
 
-    fn bind_cp_to_network(&self, cp_uuid: String, vnet_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let vnet_uuid = Uuid::parse_str(&vnet_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn bind_cp_to_network(cp_uuid: String, vnet_uuid: String) -> String {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        let vnet_uuid = parse_uuid(&vnet_uuid)?;
+        async {
+            let t = agent
                 .bind_cp_to_network(cp_uuid, vnet_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
 
-    fn unbind_cp_from_network(&self, cp_uuid: String, vnet_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let vnet_uuid = Uuid::parse_str(&vnet_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn unbind_cp_from_network(cp_uuid: String, vnet_uuid: String) -> String {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        let vnet_uuid = parse_uuid(&vnet_uuid)?;
+        async {
+            let t = agent
                 .unbind_cp_from_network(cp_uuid, vnet_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
 
-    fn get_node_uuid(&self) -> PyResult<String> {
-        task::block_on(async {
-            let t = self
-                .agent
+    fn get_node_uuid() -> String {
+        async {
+            let t = agent
                 .get_node_uuid()
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
 
-    fn register_hv_plugin(&self, plugin_uuid: String, name: String) -> PyResult<String> {
-        let plugin_uuid = Uuid::parse_str(&plugin_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn register_hv_plugin(plugin_uuid: String, name: String) -> String {
+        let plugin_uuid = parse_uuid(&plugin_uuid)?;
+        async {
+            let t = agent
                 .register_plugin(plugin_uuid, fog05_sdk::types::PluginKind::HYPERVISOR(name))
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
 
-    fn register_net_plugin(&self, plugin_uuid: String) -> PyResult<String> {
-        let plugin_uuid = Uuid::parse_str(&plugin_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn register_net_plugin(plugin_uuid: String) -> String {
+        let plugin_uuid = parse_uuid(&plugin_uuid)?;
+        async {
+            let t = agent
                 .register_plugin(plugin_uuid, fog05_sdk::types::PluginKind::NETWORKING)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
 
-    fn unregister_plugin(&self, plugin_uuid: String) -> PyResult<String> {
-        let plugin_uuid = Uuid::parse_str(&plugin_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .agent
+    fn unregister_plugin(plugin_uuid: String) -> String {
+        let plugin_uuid = parse_uuid(&plugin_uuid)?;
+        async {
+            let t = agent
                 .unregister_plugin(plugin_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
-}
 
-// Cannot use async until: https://github.com/PyO3/pyo3/pull/1406
+    unimplemented {
+        fn create_virtual_network(_vnet: VirtualNetworkConfig) -> VirtualNetwork {}
 
-#[pymethods]
-impl OsClient {
-    #[new]
-    fn new(zenoh: FosZenohSession, server_uuid: String) -> PyResult<Self> {
-        let server_uuid = Uuid::parse_str(&server_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let os = Arc::new(rustOSclient::new(zenoh.zsession.clone(), server_uuid));
-            Ok(Self { os })
-        })
-    }
-
-    #[staticmethod]
-    fn find_servers(zenoh: FosZenohSession) -> PyResult<Vec<String>> {
-        task::block_on(async {
-            let res = rustOSclient::find_servers(zenoh.zsession.clone())
-                .await
-                .map_err(zrpc_to_pyerr)?;
-            let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
-            Ok(servers)
-        })
+        fn remove_virtual_network(_vnet_uuid: String) -> String {}
     }
+}
 
-    #[staticmethod]
-    fn find_local_servers(zenoh: FosZenohSession) -> PyResult<Vec<String>> {
-        task::block_on(async {
-            let res = rustOSclient::find_local_servers(zenoh.zsession.clone())
-                .await
-                .map_err(zrpc_to_pyerr)?;
-            let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
-            Ok(servers)
-        })
-    }
+rpc_clients! {
+    OsClient, AsyncOsClient, rustOSclient, os;
 
-    fn verify_server(&self) -> PyResult<bool> {
-        task::block_on(async { self.os.verify_server().await.map_err(zrpc_to_pyerr) })
+    fn verify_server() -> bool {
+        async {
+            os.verify_server().await.map_err(zrpc_to_pyerr)
+        }
     }
 
-    fn dir_exists(&self, dir_path: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .dir_exists(dir_path)
+    fn dir_exists(dir_path: String) -> bool {
+        async {
+            os.dir_exists(dir_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn create_dir(&self, dir_path: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .create_dir(dir_path)
+    fn create_dir(dir_path: String) -> bool {
+        async {
+            os.create_dir(dir_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn rm_dir(&self, dir_path: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .rm_dir(dir_path)
+    fn rm_dir(dir_path: String) -> bool {
+        async {
+            os.rm_dir(dir_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn download_file(&self, url: String, dest_path: String) -> PyResult<bool> {
+    fn download_file(url: String, dest_path: String) -> bool {
         let url =
             url::Url::parse(&url).map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.os
-                .download_file(url, dest_path)
+        async {
+            os.download_file(url, dest_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn create_file(&self, file_path: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .create_file(file_path)
+    fn create_file(file_path: String) -> bool {
+        async {
+            os.create_file(file_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn rm_file(&self, file_path: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .rm_file(file_path)
+    fn rm_file(file_path: String) -> bool {
+        async {
+            os.rm_file(file_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn store_file(&self, content: Vec<u8>, file_path: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .store_file(content, file_path)
+    fn store_file(content: Vec<u8>, file_path: String) -> bool {
+        async {
+            os.store_file(content, file_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn read_file(&self, file_path: String) -> PyResult<Vec<u8>> {
-        task::block_on(async {
-            self.os
-                .read_file(file_path)
+    fn read_file(file_path: String) -> Vec<u8> {
+        async {
+            os.read_file(file_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn file_exists(&self, file_path: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .file_exists(file_path)
+    fn file_exists(file_path: String) -> bool {
+        async {
+            os.file_exists(file_path)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn execute_command(&self, cmd: String) -> PyResult<String> {
-        task::block_on(async {
-            self.os
-                .execute_command(cmd)
+    fn execute_command(cmd: String) -> String {
+        async {
+            os.execute_command(cmd)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn send_signal(&self, signal: u8, pid: u32) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .send_signal(signal, pid)
+    fn send_signal(signal: u8, pid: u32) -> bool {
+        async {
+            os.send_signal(signal, pid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn check_if_pid_exists(&self, pid: u32) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .check_if_pid_exists(pid)
+    fn check_if_pid_exists(pid: u32) -> bool {
+        async {
+            os.check_if_pid_exists(pid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
-    }
-
-    fn get_interface_type(&self, _iface: String) -> PyResult<String> {
-        // Export for types is needed
-        unimplemented!()
+        }
     }
 
-    fn set_interface_unavailable(&self, iface: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .set_interface_unavailable(iface)
+    fn set_interface_unavailable(iface: String) -> bool {
+        async {
+            os.set_interface_unavailable(iface)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
+        }
     }
 
-    fn set_interface_available(&self, iface: String) -> PyResult<bool> {
-        task::block_on(async {
-            self.os
-                .set_interface_available(iface)
+    fn set_interface_available(iface: String) -> bool {
+        async {
+            os.set_interface_available(iface)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
-    }
-
-    fn get_local_mgmt_address(&self, _iface: String) -> PyResult<String> {
-        // Export for types is needed
-        unimplemented!()
+        }
     }
 
-    fn get_local_mgmt_interface(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.os
-                .get_local_mgmt_interface()
+    fn get_local_mgmt_interface() -> String {
+        async {
+            os.get_local_mgmt_interface()
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)
-        })
-    }
-}
-
-#[pyproto]
-impl PyObjectProtocol for OsClient {
-    fn __str__(&self) -> PyResult<String> {
-        Ok("OSClient".to_string())
+        }
     }
 
-    fn __repr__(&self) -> PyResult<String> {
-        self.__str__()
-    }
+    unimplemented {
+        fn get_interface_type(_iface: String) -> String {
+            // Export for types is needed
+        }
 
-    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
-        self.__str__()
+        fn get_local_mgmt_address(_iface: String) -> String {
+            // Export for types is needed
+        }
     }
 }
 
-#[pymethods]
-impl NetClient {
-    #[new]
-    fn new(zenoh: FosZenohSession, server_uuid: String) -> PyResult<Self> {
-        let server_uuid = Uuid::parse_str(&server_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let net = Arc::new(rustNetworkingClient::new(
-                zenoh.zsession.clone(),
-                server_uuid,
-            ));
-            Ok(Self { net })
-        })
-    }
+rpc_clients! {
+    NetClient, AsyncNetClient, rustNetworkingClient, net;
 
-    #[staticmethod]
-    fn find_servers(zenoh: FosZenohSession) -> PyResult<Vec<String>> {
-        task::block_on(async {
-            let res = rustNetworkingClient::find_servers(zenoh.zsession.clone())
-                .await
-                .map_err(zrpc_to_pyerr)?;
-            let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
-            Ok(servers)
-        })
-    }
-
-    #[staticmethod]
-    fn find_local_servers(zenoh: FosZenohSession) -> PyResult<Vec<String>> {
-        task::block_on(async {
-            let res = rustNetworkingClient::find_local_servers(zenoh.zsession.clone())
-                .await
-                .map_err(zrpc_to_pyerr)?;
-            let servers: Vec<String> = res.iter().map(|x| format!("{}", x)).collect();
-            Ok(servers)
-        })
-    }
-
-    fn verify_server(&self) -> PyResult<bool> {
-        task::block_on(async { self.net.verify_server().await.map_err(zrpc_to_pyerr) })
+    fn verify_server() -> bool {
+        async {
+            net.verify_server().await.map_err(zrpc_to_pyerr)
+        }
     }
 
-    fn create_default_virtual_network(&self, dhcp: bool) -> PyResult<VirtualNetwork> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn create_default_virtual_network(dhcp: bool) -> VirtualNetwork {
+        async {
+            let t = net
                 .create_default_virtual_network(dhcp)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualNetwork { t })
-        })
+        }
     }
 
-    fn create_virtual_network(&self, vnet_uuid: String) -> PyResult<VirtualNetwork> {
-        let vnet_uuid = Uuid::parse_str(&vnet_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn create_virtual_network(vnet_uuid: String) -> VirtualNetwork {
+        let vnet_uuid = parse_uuid(&vnet_uuid)?;
+        async {
+            let t = net
                 .create_virtual_network(vnet_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualNetwork { t })
-        })
+        }
     }
 
-    fn get_virtual_network(&self, vnet_uuid: String) -> PyResult<VirtualNetwork> {
-        let vnet_uuid = Uuid::parse_str(&vnet_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_virtual_network(vnet_uuid: String) -> VirtualNetwork {
+        let vnet_uuid = parse_uuid(&vnet_uuid)?;
+        async {
+            let t = net
                 .get_virtual_network(vnet_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualNetwork { t })
-        })
+        }
     }
 
-    fn delete_virtual_network(&self, vnet_uuid: String) -> PyResult<VirtualNetwork> {
-        let vnet_uuid = Uuid::parse_str(&vnet_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn delete_virtual_network(vnet_uuid: String) -> VirtualNetwork {
+        let vnet_uuid = parse_uuid(&vnet_uuid)?;
+        async {
+            let t = net
                 .delete_virtual_network(vnet_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualNetwork { t })
-        })
+        }
     }
 
-    fn create_connection_point(&self) -> PyResult<ConnectionPoint> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn create_connection_point() -> ConnectionPoint {
+        async {
+            let t = net
                 .create_connection_point()
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(ConnectionPoint { t })
-        })
+        }
     }
 
-    fn get_connection_point(&self, cp_uuid: String) -> PyResult<ConnectionPoint> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_connection_point(cp_uuid: String) -> ConnectionPoint {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        async {
+            let t = net
                 .get_connection_point(cp_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(ConnectionPoint { t })
-        })
+        }
     }
 
-    fn delete_connection_point(&self, cp_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn delete_connection_point(cp_uuid: String) -> String {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        async {
+            let t = net
                 .delete_connection_point(cp_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(format!("{}", t))
-        })
+        }
     }
 
-    fn create_virtual_interface(&self, intf: VirtualInterfaceConfig) -> PyResult<VirtualInterface> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn create_virtual_interface(intf: VirtualInterfaceConfig) -> VirtualInterface {
+        async {
+            let t = net
                 .create_virtual_interface(intf.t)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn get_virtual_interface(&self, intf_uuid: String) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_virtual_interface(intf_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .get_virtual_interface(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn delete_virtual_interface(&self, intf_uuid: String) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn delete_virtual_interface(intf_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .delete_virtual_interface(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn create_virtual_bridge(&self, br_name: String) -> PyResult<VirtualInterface> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn create_virtual_bridge(br_name: String) -> VirtualInterface {
+        async {
+            let t = net
                 .create_virtual_bridge(br_name)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn get_virtual_bridge(&self, intf_uuid: String) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_virtual_bridge(intf_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .get_virtual_bridge(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn delete_virtual_bridge(&self, intf_uuid: String) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn delete_virtual_bridge(intf_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .delete_virtual_bridge(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn create_network_namespace(&self) -> PyResult<NetworkNamespace> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn create_network_namespace() -> NetworkNamespace {
+        async {
+            let t = net
                 .create_network_namespace()
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(NetworkNamespace { t })
-        })
+        }
     }
 
-    fn get_network_namespace(&self, ns_uuid: String) -> PyResult<NetworkNamespace> {
-        let ns_uuid = Uuid::parse_str(&ns_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_network_namespace(ns_uuid: String) -> NetworkNamespace {
+        let ns_uuid = parse_uuid(&ns_uuid)?;
+        async {
+            let t = net
                 .get_network_namespace(ns_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(NetworkNamespace { t })
-        })
+        }
     }
 
-    fn delete_network_namespace(&self, ns_uuid: String) -> PyResult<NetworkNamespace> {
-        let ns_uuid = Uuid::parse_str(&ns_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn delete_network_namespace(ns_uuid: String) -> NetworkNamespace {
+        let ns_uuid = parse_uuid(&ns_uuid)?;
+        async {
+            let t = net
                 .delete_network_namespace(ns_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(NetworkNamespace { t })
-        })
+        }
     }
 
-    fn bind_interface_to_connection_point(
-        &self,
-        intf_uuid: String,
-        cp_uuid: String,
-    ) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn bind_interface_to_connection_point(intf_uuid: String, cp_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        async {
+            let t = net
                 .bind_interface_to_connection_point(intf_uuid, cp_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
     fn unbind_interface_from_connection_point(
-        &self,
         intf_uuid: String,
         cp_uuid: String,
-    ) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
-                .bind_interface_to_connection_point(intf_uuid, cp_uuid)
+    ) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        async {
+            let t = net
+                .unbind_interface_from_connection_point(intf_uuid, cp_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
     fn bind_connection_point_to_virtual_network(
-        &self,
         intf_uuid: String,
         vnet_uuid: String,
-    ) -> PyResult<ConnectionPoint> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let vnet_uuid = Uuid::parse_str(&vnet_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    ) -> ConnectionPoint {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        let vnet_uuid = parse_uuid(&vnet_uuid)?;
+        async {
+            let t = net
                 .bind_connection_point_to_virtual_network(intf_uuid, vnet_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(ConnectionPoint { t })
-        })
+        }
     }
 
     fn unbind_connection_point_from_virtual_network(
-        &self,
         intf_uuid: String,
         vnet_uuid: String,
-    ) -> PyResult<ConnectionPoint> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let vnet_uuid = Uuid::parse_str(&vnet_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    ) -> ConnectionPoint {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        let vnet_uuid = parse_uuid(&vnet_uuid)?;
+        async {
+            let t = net
                 .unbind_connection_point_from_virtual_network(intf_uuid, vnet_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(ConnectionPoint { t })
-        })
+        }
     }
 
-    fn get_interface_addresses(&self, intf_uuid: String) -> PyResult<Vec<String>> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_interface_addresses(intf_uuid: String) -> Vec<String> {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .get_interface_addresses(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             let addresses: Vec<String> = t.iter().map(|x| format!("{}", x)).collect();
             Ok(addresses)
-        })
+        }
     }
 
-    fn get_overlay_iface(&self) -> PyResult<String> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_overlay_iface() -> String {
+        async {
+            let t = net
                 .get_overlay_iface()
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(t)
-        })
+        }
     }
 
-    fn get_vlan_face(&self) -> PyResult<String> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn get_vlan_face() -> String {
+        async {
+            let t = net
                 .get_vlan_face()
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(t)
-        })
+        }
     }
 
-    fn create_macvlan_interface(&self, master_intf: String) -> PyResult<VirtualInterface> {
-        task::block_on(async {
-            let t = self
-                .net
+    fn create_macvlan_interface(master_intf: String) -> VirtualInterface {
+        async {
+            let t = net
                 .create_macvlan_interface(master_intf)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn delete_macvan_interface(&self, intf_uuid: String) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn delete_macvan_interface(intf_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .delete_macvan_interface(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn move_interface_info_namespace(
-        &self,
-        intf_uuid: String,
-        ns_uuid: String,
-    ) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let ns_uuid = Uuid::parse_str(&ns_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn move_interface_info_namespace(intf_uuid: String, ns_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        let ns_uuid = parse_uuid(&ns_uuid)?;
+        async {
+            let t = net
                 .move_interface_info_namespace(intf_uuid, ns_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn move_interface_into_default_namespace(
-        &self,
-        intf_uuid: String,
-    ) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn move_interface_into_default_namespace(intf_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .move_interface_into_default_namespace(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn rename_virtual_interface(
-        &self,
-        intf_uuid: String,
-        intf_name: String,
-    ) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn rename_virtual_interface(intf_uuid: String, intf_name: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .rename_virtual_interface(intf_uuid, intf_name)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn attach_interface_to_bridge(
-        &self,
-        intf_uuid: String,
-        br_uuid: String,
-    ) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let br_uuid = Uuid::parse_str(&br_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn attach_interface_to_bridge(intf_uuid: String, br_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        let br_uuid = parse_uuid(&br_uuid)?;
+        async {
+            let t = net
                 .attach_interface_to_bridge(intf_uuid, br_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn detach_interface_from_bridge(&self, intf_uuid: String) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    fn detach_interface_from_bridge(intf_uuid: String) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        async {
+            let t = net
                 .detach_interface_from_bridge(intf_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
     fn create_virtual_interface_in_namespace(
-        &self,
         intf: VirtualInterfaceConfig,
         ns_uuid: String,
-    ) -> PyResult<VirtualInterface> {
-        let ns_uuid = Uuid::parse_str(&ns_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    ) -> VirtualInterface {
+        let ns_uuid = parse_uuid(&ns_uuid)?;
+        async {
+            let t = net
                 .create_virtual_interface_in_namespace(intf.t, ns_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
     fn delete_virtual_interface_in_namespace(
-        &self,
         intf_uuid: String,
         ns_uuid: String,
-    ) -> PyResult<VirtualInterface> {
-        let intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let ns_uuid = Uuid::parse_str(&ns_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            let t = self
-                .net
+    ) -> VirtualInterface {
+        let intf_uuid = parse_uuid(&intf_uuid)?;
+        let ns_uuid = parse_uuid(&ns_uuid)?;
+        async {
+            let t = net
                 .delete_virtual_interface_in_namespace(intf_uuid, ns_uuid)
                 .await
                 .map_err(zrpc_to_pyerr)?
                 .map_err(to_pyerr)?;
             Ok(VirtualInterface { t })
-        })
+        }
     }
 
-    fn assing_address_to_interface(
-        &self,
-        intf_uuid: String,
-        _address: Option<String>,
-    ) -> PyResult<VirtualInterface> {
-        let _intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        unimplemented!();
+    unimplemented {
+        fn assing_address_to_interface(
+            intf_uuid: String,
+            _address: Option<String>,
+        ) -> VirtualInterface {
+            let _intf_uuid = parse_uuid(&intf_uuid)?;
+        }
+
+        fn remove_address_from_interface(intf_uuid: String, _address: String) -> VirtualInterface {
+            let _intf_uuid = parse_uuid(&intf_uuid)?;
+        }
+
+        fn set_macaddres_of_interface(intf_uuid: String, _address: String) -> VirtualInterface {
+            let _intf_uuid = parse_uuid(&intf_uuid)?;
+        }
     }
+}
 
-    fn remove_address_from_interface(
-        &self,
-        intf_uuid: String,
-        _address: String,
-    ) -> PyResult<VirtualInterface> {
-        let _intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        unimplemented!()
+#[pyproto]
+impl PyObjectProtocol for AsyncAgentClient {
+    fn __str__(&self) -> PyResult<String> {
+        Ok("AsyncAgentClient".to_string())
     }
 
-    fn set_macaddres_of_interface(
-        &self,
-        intf_uuid: String,
-        _address: String,
-    ) -> PyResult<VirtualInterface> {
-        let _intf_uuid = Uuid::parse_str(&intf_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        unimplemented!()
+    fn __repr__(&self) -> PyResult<String> {
+        self.__str__()
+    }
+
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        self.__str__()
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for OsClient {
+    fn __str__(&self) -> PyResult<String> {
+        Ok("OSClient".to_string())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        self.__str__()
+    }
+
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        self.__str__()
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AsyncOsClient {
+    fn __str__(&self) -> PyResult<String> {
+        Ok("AsyncOSClient".to_string())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        self.__str__()
+    }
+
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        self.__str__()
     }
 }
 
@@ -1108,6 +1109,21 @@ impl PyObjectProtocol for NetClient {
     }
 }
 
+#[pyproto]
+impl PyObjectProtocol for AsyncNetClient {
+    fn __str__(&self) -> PyResult<String> {
+        Ok("AsyncNetClient".to_string())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        self.__str__()
+    }
+
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        self.__str__()
+    }
+}
+
 pub struct PluginState {
     pub handler: Option<async_std::task::JoinHandle<()>>,
     pub instance_uuid: Option<Uuid>,
@@ -1121,6 +1137,7 @@ pub struct WrappedHypervisor {
     sender: async_std::channel::Sender<()>,
     receiver: async_std::channel::Receiver<()>,
     state: Arc<RwLock<PluginState>>,
+    running: Arc<AtomicBool>,
 }
 
 #[pymethods]
@@ -1140,67 +1157,148 @@ impl WrappedHypervisor {
             sender,
             receiver,
             state: Arc::new(RwLock::new(state)),
+            running: Arc::new(AtomicBool::new(false)),
         }
     }
 
-    fn run(&self) {
+    /// Serves in the calling thread until `stop` is called. The GIL is
+    /// released meanwhile, as the callbacks need it.
+    fn run(&self) -> PyResult<()> {
+        self.set_running()?;
+        block_on_without_gil(self.serve())
+    }
+
+    fn start(&self) -> PyResult<()> {
+        self.z.handles()?;
+        self.set_running()?;
+        // The logger may have been set already by a previous start
+        let _ = env_logger::try_init_from_env(
+            env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+        );
+
+        let plugin = self.clone();
+        let h = task::spawn_blocking(move || {
+            if let Err(e) = task::block_on(plugin.serve()) {
+                error!("WrappedHypervisor main loop failed: {}", e);
+            }
+        });
+        task::block_on(async {
+            let mut guard = self.state.write().await;
+            guard.handler = Some(h)
+        });
+        Ok(())
+    }
+
+    fn stop(&self) -> PyResult<()> {
+        if !self.running.load(Ordering::SeqCst) {
+            return Err(PyErr::new::<crate::FError, _>(
+                "WrappedHypervisor is not running",
+            ));
+        }
+        self.sender.try_send(()).map_err(|err| match err {
+            TrySendError::Full(_) => {
+                PyErr::new::<crate::FError, _>("WrappedHypervisor is already stopping")
+            }
+            TrySendError::Closed(_) => {
+                PyErr::new::<crate::FError, _>("WrappedHypervisor is not running")
+            }
+        })
+    }
+
+    fn instance_uuid(&self) -> PyResult<String> {
         task::block_on(async {
+            let guard = self.state.read().await;
+            match guard.instance_uuid {
+                Some(instance_uuid) => Ok(format!("{}", instance_uuid)),
+                None => Err(PyErr::new::<crate::FError, _>(
+                    "WrappedHypervisor is not started",
+                )),
+            }
+        })
+    }
+}
+
+impl WrappedHypervisor {
+    fn set_running(&self) -> PyResult<()> {
+        if self.running.swap(true, Ordering::SeqCst) {
+            return Err(PyErr::new::<crate::FError, _>(
+                "WrappedHypervisor is already running",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Serves until stopped, `running` must have been set by the caller.
+    async fn serve(&self) -> PyResult<()> {
+        // Also resets the state when `run` is interrupted and drops the future
+        let _running = RunningGuard(self);
+        self.serve_loop().await
+    }
+
+    async fn serve_loop(&self) -> PyResult<()> {
+        let mut instance_uuid = None;
+        loop {
+            let generation = self.z.generation();
+            let zsession = self.z.current_handles().await?.zsession;
             let hv_server = self
                 .clone()
-                .get_hypervisor_plugin_server(self.z.zsession.clone(), None);
+                .get_hypervisor_plugin_server(zsession, instance_uuid);
 
-            let (stopper, _h) = hv_server.connect().await.unwrap();
-            hv_server.initialize().await.unwrap();
+            let (stopper, _h) = hv_server.connect().await.map_err(zrpc_to_pyerr)?;
+            hv_server.initialize().await.map_err(zrpc_to_pyerr)?;
 
             let mut guard = self.state.write().await;
             guard.instance_uuid = Some(hv_server.instance_uuid());
             drop(guard);
-            hv_server.register().await.unwrap();
+            instance_uuid = Some(hv_server.instance_uuid());
+            hv_server.register().await.map_err(zrpc_to_pyerr)?;
 
-            let (shv, _hhv) = hv_server.start().await.unwrap();
+            let (shv, _hhv) = hv_server.start().await.map_err(zrpc_to_pyerr)?;
 
+            // Ends when the session is re-established after a disconnection
             let monitoring = async {
-                loop {
-                    info!("Monitoring loop started");
-                    task::sleep(Duration::from_secs(60)).await;
+                info!("Monitoring loop started");
+                while self.z.generation() == generation {
+                    task::sleep(Duration::from_secs(1)).await;
                 }
+                true
             };
 
-            match monitoring.race(self.receiver.recv()).await {
-                Ok(_) => trace!("Monitoring ending correct"),
-                Err(e) => trace!("Monitoring ending got error: {}", e),
+            let stopping = async {
+                match self.receiver.recv().await {
+                    Ok(_) => trace!("Monitoring ending correct"),
+                    Err(e) => trace!("Monitoring ending got error: {}", e),
+                }
+                false
+            };
+
+            if monitoring.race(stopping).await {
+                // The server is bound to the old session, that is gone already,
+                // serve again on the new one keeping the same instance UUID.
+                info!("FosZenohSession reconnected, restarting WrappedHypervisor");
+                let _ = hv_server.stop(shv).await;
+                let _ = hv_server.disconnect(stopper).await;
+                continue;
             }
 
-            hv_server.stop(shv).await.unwrap();
-            hv_server.unregister().await.unwrap();
-            hv_server.disconnect(stopper).await.unwrap();
+            hv_server.stop(shv).await.map_err(zrpc_to_pyerr)?;
+            hv_server.unregister().await.map_err(zrpc_to_pyerr)?;
+            hv_server.disconnect(stopper).await.map_err(zrpc_to_pyerr)?;
+            break;
+        }
 
-            info!("WrappedHypervisor main loop exiting")
-        })
-    }
-
-    fn start(&self) {
-        env_logger::init_from_env(
-            env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
-        );
-
-        let plugin = self.clone();
-        let h = task::spawn_blocking(move || plugin.run());
-        task::block_on(async {
-            let mut guard = self.state.write().await;
-            guard.handler = Some(h)
-        });
+        info!("WrappedHypervisor main loop exiting");
+        Ok(())
     }
+}
 
-    fn stop(&self) {
-        task::block_on(async { self.sender.send(()).await.unwrap() })
-    }
+struct RunningGuard<'a>(&'a WrappedHypervisor);
 
-    fn instance_uuid(&self) -> PyResult<String> {
-        task::block_on(async {
-            let guard = self.state.read().await;
-            Ok(format!("{}", guard.instance_uuid.unwrap()))
-        })
+impl Drop for RunningGuard<'_> {
+    fn drop(&mut self) {
+        // A stop request left over must not end the next run
+        while self.0.receiver.try_recv().is_ok() {}
+        self.0.running.store(false, Ordering::SeqCst);
     }
 }
 
@@ -1213,10 +1311,10 @@ impl HypervisorPlugin for WrappedHypervisor {
         let r = self
             .hv
             .call_method(py, "define_fdu", cb_args, None)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "define_fdu", e))?;
         let record: crate::im::FduRecord = r
             .extract(py)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "define_fdu", e))?;
         Ok(record.r)
     }
 
@@ -1227,10 +1325,10 @@ impl HypervisorPlugin for WrappedHypervisor {
         let r = self
             .hv
             .call_method(py, "undefine_fdu", cb_args, None)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "undefine_fdu", e))?;
         let uuid: String = r
             .extract(py)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "undefine_fdu", e))?;
         let uuid = Uuid::parse_str(&uuid).map_err(|e| FError::HypervisorError(format!("{}", e)))?;
         Ok(uuid)
     }
@@ -1242,10 +1340,10 @@ impl HypervisorPlugin for WrappedHypervisor {
         let r = self
             .hv
             .call_method(py, "configure_fdu", cb_args, None)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "configure_fdu", e))?;
         let uuid: String = r
             .extract(py)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "configure_fdu", e))?;
         let uuid = Uuid::parse_str(&uuid).map_err(|e| FError::HypervisorError(format!("{}", e)))?;
         Ok(uuid)
     }
@@ -1257,10 +1355,10 @@ impl HypervisorPlugin for WrappedHypervisor {
         let r = self
             .hv
             .call_method(py, "clean_fdu", cb_args, None)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "clean_fdu", e))?;
         let uuid: String = r
             .extract(py)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "clean_fdu", e))?;
         let uuid = Uuid::parse_str(&uuid).map_err(|e| FError::HypervisorError(format!("{}", e)))?;
         Ok(uuid)
     }
@@ -1272,10 +1370,10 @@ impl HypervisorPlugin for WrappedHypervisor {
         let r = self
             .hv
             .call_method(py, "start_fdu", cb_args, None)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "start_fdu", e))?;
         let uuid: String = r
             .extract(py)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "start_fdu", e))?;
         let uuid = Uuid::parse_str(&uuid).map_err(|e| FError::HypervisorError(format!("{}", e)))?;
         Ok(uuid)
     }
@@ -1303,10 +1401,10 @@ impl HypervisorPlugin for WrappedHypervisor {
         let r = self
             .hv
             .call_method(py, "stop_fdu", cb_args, None)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "stop_fdu", e))?;
         let uuid: String = r
             .extract(py)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "stop_fdu", e))?;
         let uuid = Uuid::parse_str(&uuid).map_err(|e| FError::HypervisorError(format!("{}", e)))?;
         Ok(uuid)
     }
@@ -1322,10 +1420,40 @@ impl HypervisorPlugin for WrappedHypervisor {
         let r = self
             .hv
             .call_method(py, "get_fdu_status", cb_args, None)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "get_fdu_status", e))?;
         let record: crate::im::FduRecord = r
             .extract(py)
-            .map_err(|e| FError::HypervisorError(format!("{}", e)))?;
+            .map_err(|e| callback_error(py, "get_fdu_status", e))?;
         Ok(record.r)
     }
 }
+
+/// Turns the exception raised by a Python hypervisor `method` into an
+/// `FError::HypervisorError` holding its type, message and traceback.
+fn callback_error(py: Python, method: &str, err: PyErr) -> FError {
+    let value = err.to_object(py);
+    let name = value.as_ref(py).get_type().name().to_string();
+    let traceback = py
+        .import("traceback")
+        .and_then(|tb| {
+            tb.call_method1(
+                "format_exception",
+                (
+                    value.as_ref(py).get_type(),
+                    value.clone_ref(py),
+                    value.getattr(py, "__traceback__")?,
+                ),
+            )?
+            .extract::<Vec<String>>()
+        })
+        .map(|lines| lines.concat())
+        .unwrap_or_else(|_| format!("{}: {}\n", name, err));
+    error!("Hypervisor {} raised {}", method, traceback.trim_end());
+    FError::HypervisorError(format!(
+        "{} raised {}: {}\n{}",
+        method,
+        name,
+        value.as_ref(py),
+        traceback
+    ))
+}
@@ -12,14 +12,13 @@
 *********************************************************************************/
 
 use async_std::sync::Arc;
-use async_std::task;
-use fog05_sdk::zconnector::{Global as rustGlobal, local::Local as rustLocal};
+use fog05_sdk::zconnector::{local::Local as rustLocal, Global as rustGlobal};
 use pyo3::prelude::*;
 use pyo3::PyObjectProtocol;
 use uuid::Uuid;
 
-use crate::to_pyerr;
 use crate::FosZenohSession;
+use crate::{parse_uuid, to_pyerr};
 
 #[pyclass]
 #[derive(Clone)]
@@ -29,14 +28,20 @@ pub struct ZConnector {
     pub global: Arc<Global>,
 }
 
+/// Node local view of the fog05 state. Results are returned as native Python
+/// values, like `to_dict` does for the information model types.
 #[pyclass]
 pub struct Local {
-    pub t: rustLocal,
+    pub z: FosZenohSession,
+    pub node_uuid: Uuid,
 }
 
+/// System wide view of the fog05 state, results are returned like in `Local`.
 #[pyclass]
 pub struct Global {
-    pub t: rustGlobal,
+    pub z: FosZenohSession,
+    pub sys_id: Option<Uuid>,
+    pub tenant_id: Option<Uuid>,
 }
 
 #[pymodule]
@@ -51,68 +56,79 @@ pub fn zconnector(_py: Python, m: &PyModule) -> PyResult<()> {
 #[pymethods]
 impl ZConnector {
     #[new]
-    pub fn new(z: FosZenohSession, sys_id: Option<String>, tenant_id: Option<String>) -> Self {
+    pub fn new(
+        z: FosZenohSession,
+        sys_id: Option<String>,
+        tenant_id: Option<String>,
+    ) -> PyResult<Self> {
         let sys_id = match sys_id {
-            Some(sys_id) => Some(Uuid::parse_str(&sys_id).unwrap()),
-            None => None
+            Some(sys_id) => Some(parse_uuid(&sys_id)?),
+            None => z.config.system_id,
         };
         let tenant_id = match tenant_id {
-            Some(tenant_id) => Some(Uuid::parse_str(&tenant_id).unwrap()),
-            None => None
+            Some(tenant_id) => Some(parse_uuid(&tenant_id)?),
+            None => z.config.tenant_id,
         };
-        Self {
-            z : Arc::new(z.clone()),
-            global: Arc::new(Global{t: rustGlobal::new(z.zenoh.clone(), sys_id, tenant_id)}),
+        let node_uuid = fog05_sdk::get_node_uuid().map_err(to_pyerr)?;
+        Ok(Self {
+            z: Arc::new(z.clone()),
+            global: Arc::new(Global {
+                z: z.clone(),
+                sys_id,
+                tenant_id,
+            }),
             // We should get the node UUID from zenoh
-            local: Arc::new(Local{t: rustLocal::new(z.zenoh, fog05_sdk::get_node_uuid().unwrap())}),
-        }
+            local: Arc::new(Local { z, node_uuid }),
+        })
     }
 }
 
 #[pymethods]
 impl Global {
-
-    fn get_system_info(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_system_info().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_system_info(&self) -> PyResult<PyObject> {
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_system_info().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_system_config(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_system_config().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_system_config(&self) -> PyResult<PyObject> {
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_system_config().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_nodes(&self) -> PyResult<Vec<String>> {
-        task::block_on(async {
-            self.t.get_all_nodes().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_all_nodes(&self) -> PyResult<PyObject> {
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_all_nodes().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_node_info(&self, node_uuid: String) -> PyResult<String> {
-        let node_uuid = Uuid::parse_str(&node_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_node_info(node_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_node_info(&self, node_uuid: String) -> PyResult<PyObject> {
+        let node_uuid = parse_uuid(&node_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_node_info(node_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_node_info(&self, node_uuid: String) -> PyResult<String> {
-        let node_uuid = Uuid::parse_str(&node_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.remove_node_info(node_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_node_info(&self, node_uuid: String) -> PyResult<PyObject> {
+        let node_uuid = parse_uuid(&node_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_node_info(node_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_node_info(&self, node_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_node_info"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -121,29 +137,26 @@ impl Global {
         // })
     }
 
-    fn get_node_status(&self, node_uuid: String) -> PyResult<String> {
-        let node_uuid = Uuid::parse_str(&node_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_node_status(node_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_node_status(&self, node_uuid: String) -> PyResult<PyObject> {
+        let node_uuid = parse_uuid(&node_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_node_status(node_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_node_status(&self, node_uuid: String) -> PyResult<String> {
-        let node_uuid = Uuid::parse_str(&node_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_node_status(node_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_node_status(&self, node_uuid: String) -> PyResult<PyObject> {
+        let node_uuid = parse_uuid(&node_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_node_status(node_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_node_status(&self, node_status: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_node_status"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -152,34 +165,30 @@ impl Global {
         // })
     }
 
-    fn get_plugin(&self, node_uuid: String, plugin_uuid: String) -> PyResult<String> {
-        let node_uuid = Uuid::parse_str(&node_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        let plugin_uuid = Uuid::parse_str(&plugin_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
+    fn get_plugin(&self, node_uuid: String, plugin_uuid: String) -> PyResult<PyObject> {
+        let node_uuid = parse_uuid(&node_uuid)?;
+        let plugin_uuid = parse_uuid(&plugin_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t
                 .get_plugin(node_uuid, plugin_uuid)
                 .await
                 .map_err(to_pyerr)?;
-            unimplemented!()
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_virtual_network(&self, net_uuid: String) -> PyResult<String> {
-        let net_uuid = Uuid::parse_str(&net_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .get_virtual_network(net_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_virtual_network(&self, net_uuid: String) -> PyResult<PyObject> {
+        let net_uuid = parse_uuid(&net_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_virtual_network(net_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_virutal_network(&self, vnet_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_virutal_network"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -188,32 +197,26 @@ impl Global {
         // })
     }
 
-    fn remove_virtual_network(&self, net_uuid: String) -> PyResult<String> {
-        let net_uuid = Uuid::parse_str(&net_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_virtual_network(net_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_virtual_network(&self, net_uuid: String) -> PyResult<PyObject> {
+        let net_uuid = parse_uuid(&net_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_virtual_network(net_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_connection_point(&self, cp_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .get_connection_point(cp_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_connection_point(&self, cp_uuid: String) -> PyResult<PyObject> {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_connection_point(cp_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_connection_point(&self, cp_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_connection_point"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -222,29 +225,26 @@ impl Global {
         // })
     }
 
-    fn remove_connection_point(&self, cp_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_connection_point(cp_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_connection_point(&self, cp_uuid: String) -> PyResult<PyObject> {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_connection_point(cp_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_interface(&self, iface_uuid: String) -> PyResult<String> {
-        let iface_uuid = Uuid::parse_str(&iface_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_interface(iface_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_interface(&self, iface_uuid: String) -> PyResult<PyObject> {
+        let iface_uuid = parse_uuid(&iface_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_interface(iface_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_interface(&self, iface_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_interface"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -253,36 +253,34 @@ impl Global {
         // })
     }
 
-    fn remove_interface(&self, iface_uuid: String) -> PyResult<String> {
-        let iface_uuid = Uuid::parse_str(&iface_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_interface(iface_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_interface(&self, iface_uuid: String) -> PyResult<PyObject> {
+        let iface_uuid = parse_uuid(&iface_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_interface(iface_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_fdu(&self, fdu_uuid: String) -> PyResult<String> {
-        let fdu_uuid = Uuid::parse_str(&fdu_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_fdu(fdu_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_fdu(&self, fdu_uuid: String) -> PyResult<PyObject> {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_fdu(fdu_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_fdu(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_all_fdu().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_all_fdu(&self) -> PyResult<PyObject> {
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_all_fdu().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_fdu(&self, fdu_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_fdu"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -291,45 +289,46 @@ impl Global {
         // })
     }
 
-    fn remove_fdu(&self, fdu_uuid: String) -> PyResult<String> {
-        let fdu_uuid = Uuid::parse_str(&fdu_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.remove_fdu(fdu_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_fdu(&self, fdu_uuid: String) -> PyResult<PyObject> {
+        let fdu_uuid = parse_uuid(&fdu_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_fdu(fdu_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_instance(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_instance(instance_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_instance(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_instance(instance_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_fdu_instances(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
+    fn get_all_fdu_instances(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t
                 .get_all_fdu_instances(instance_uuid)
                 .await
                 .map_err(to_pyerr)?;
-            unimplemented!()
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_instances(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_all_instances().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_all_instances(&self) -> PyResult<PyObject> {
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_all_instances().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_instance(&self, add_instance: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_instance"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -338,36 +337,34 @@ impl Global {
         // })
     }
 
-    fn remove_instance(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_instance(instance_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_instance(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_instance(instance_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_entity(&self, entity_uuid: String) -> PyResult<String> {
-        let entity_uuid = Uuid::parse_str(&entity_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_entity(entity_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_entity(&self, entity_uuid: String) -> PyResult<PyObject> {
+        let entity_uuid = parse_uuid(&entity_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_entity(entity_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_entity(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_all_entity().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_all_entity(&self) -> PyResult<PyObject> {
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_all_entity().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_entity(&self, entity_uuid: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_entity"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -376,51 +373,49 @@ impl Global {
         // })
     }
 
-    fn remove_entity(&self, entity_uuid: String) -> PyResult<String> {
-        let entity_uuid = Uuid::parse_str(&entity_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.remove_entity(entity_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_entity(&self, entity_uuid: String) -> PyResult<PyObject> {
+        let entity_uuid = parse_uuid(&entity_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.remove_entity(entity_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_entity_instance(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
+    fn get_entity_instance(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t
                 .get_entity_instance(instance_uuid)
                 .await
                 .map_err(to_pyerr)?;
-            unimplemented!()
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_entity_instances(&self, entity_uuid: String) -> PyResult<String> {
-        let entity_uuid = Uuid::parse_str(&entity_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
+    fn get_all_entity_instances(&self, entity_uuid: String) -> PyResult<PyObject> {
+        let entity_uuid = parse_uuid(&entity_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t
                 .get_all_entity_instances(entity_uuid)
                 .await
                 .map_err(to_pyerr)?;
-            unimplemented!()
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_entities_instances(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t
-                .get_all_entities_instances()
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_all_entities_instances(&self) -> PyResult<PyObject> {
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t.get_all_entities_instances().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_entity_instance(&self, instance_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Global.add_entity_instance"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -429,19 +424,26 @@ impl Global {
         // })
     }
 
-    fn remove_entity_instance(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
+    fn remove_entity_instance(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.global()?;
+        self.z.call(None, async {
+            let r = t
                 .remove_entity_instance(instance_uuid)
                 .await
                 .map_err(to_pyerr)?;
-            unimplemented!()
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 }
 
+impl Global {
+    fn global(&self) -> PyResult<rustGlobal> {
+        let zenoh = self.z.handles()?.zenoh;
+        Ok(rustGlobal::new(zenoh, self.sys_id, self.tenant_id))
+    }
+}
+
 #[pyproto]
 impl PyObjectProtocol for Global {
     fn __str__(&self) -> PyResult<String> {
@@ -459,23 +461,24 @@ impl PyObjectProtocol for Global {
 
 #[pymethods]
 impl Local {
-
-    fn get_node_info(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_node_info().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_node_info(&self) -> PyResult<PyObject> {
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_node_info().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_node_info(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.remove_node_info().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_node_info(&self) -> PyResult<PyObject> {
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.remove_node_info().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_node_info(&self, node_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_node_info"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -484,22 +487,24 @@ impl Local {
         // })
     }
 
-    fn get_node_status(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_node_status().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_node_status(&self) -> PyResult<PyObject> {
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_node_status().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_node_status(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.remove_node_status().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_node_status(&self) -> PyResult<PyObject> {
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.remove_node_status().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_node_status(&self, node_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_node_status"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -508,26 +513,26 @@ impl Local {
         // })
     }
 
-    fn get_plugin(&self, plugin_uuid: String) -> PyResult<String> {
-        let plugin_uuid = Uuid::parse_str(&plugin_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_plugin(plugin_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_plugin(&self, plugin_uuid: String) -> PyResult<PyObject> {
+        let plugin_uuid = parse_uuid(&plugin_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_plugin(plugin_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_plugin(&self, plugin_uuid: String) -> PyResult<String> {
-        let plugin_uuid = Uuid::parse_str(&plugin_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.remove_plugin(plugin_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_plugin(&self, plugin_uuid: String) -> PyResult<PyObject> {
+        let plugin_uuid = parse_uuid(&plugin_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.remove_plugin(plugin_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_plugin(&self, plugin_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_plugin"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -536,32 +541,26 @@ impl Local {
         // })
     }
 
-    fn get_virtual_network(&self, net_uuid: String) -> PyResult<String> {
-        let net_uuid = Uuid::parse_str(&net_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .get_virtual_network(net_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_virtual_network(&self, net_uuid: String) -> PyResult<PyObject> {
+        let net_uuid = parse_uuid(&net_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_virtual_network(net_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_virtual_network(&self, net_uuid: String) -> PyResult<String> {
-        let net_uuid = Uuid::parse_str(&net_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_virtual_network(net_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_virtual_network(&self, net_uuid: String) -> PyResult<PyObject> {
+        let net_uuid = parse_uuid(&net_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.remove_virtual_network(net_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_virutal_network(&self, vnet_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_virutal_network"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -570,32 +569,26 @@ impl Local {
         // })
     }
 
-    fn get_connection_point(&self, cp_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .get_connection_point(cp_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_connection_point(&self, cp_uuid: String) -> PyResult<PyObject> {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_connection_point(cp_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_connection_point(&self, cp_uuid: String) -> PyResult<String> {
-        let cp_uuid = Uuid::parse_str(&cp_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_connection_point(cp_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_connection_point(&self, cp_uuid: String) -> PyResult<PyObject> {
+        let cp_uuid = parse_uuid(&cp_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.remove_connection_point(cp_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_connection_point(&self, cp_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_connection_point"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -604,36 +597,34 @@ impl Local {
         // }
     }
 
-    fn get_interface(&self, iface_uuid: String) -> PyResult<String> {
-        let iface_uuid = Uuid::parse_str(&iface_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_interface(iface_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_interface(&self, iface_uuid: String) -> PyResult<PyObject> {
+        let iface_uuid = parse_uuid(&iface_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_interface(iface_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_interfaces(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_all_interfaces().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_all_interfaces(&self) -> PyResult<PyObject> {
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_all_interfaces().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_interface(&self, iface_uuid: String) -> PyResult<String> {
-        let iface_uuid = Uuid::parse_str(&iface_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_interface(iface_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_interface(&self, iface_uuid: String) -> PyResult<PyObject> {
+        let iface_uuid = parse_uuid(&iface_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.remove_interface(iface_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_interface(&self, intf_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_interface"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -642,36 +633,37 @@ impl Local {
         // }
     }
 
-    fn get_instance(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t.get_instance(instance_uuid).await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_instance(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_instance(instance_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_fdu_instances(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
+    fn get_all_fdu_instances(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t
                 .get_all_fdu_instances(instance_uuid)
                 .await
                 .map_err(to_pyerr)?;
-            unimplemented!()
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_all_instances(&self) -> PyResult<String> {
-        task::block_on(async {
-            self.t.get_all_instances().await.map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_all_instances(&self) -> PyResult<PyObject> {
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_all_instances().await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_instance(&self, instance_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_instance"))
         // let node_uuid = Uuid::parse_str(&node_uuid)
         //     .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
         // task::block_on(async {
@@ -680,44 +672,38 @@ impl Local {
         // })
     }
 
-    fn remove_instance(&self, instance_uuid: String) -> PyResult<String> {
-        let instance_uuid = Uuid::parse_str(&instance_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .remove_instance(instance_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn remove_instance(&self, instance_uuid: String) -> PyResult<PyObject> {
+        let instance_uuid = parse_uuid(&instance_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.remove_instance(instance_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn get_network_namespace(&self, ns_uuid: String) -> PyResult<String> {
-        let ns_uuid = Uuid::parse_str(&ns_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
-                .get_network_namespace(ns_uuid)
-                .await
-                .map_err(to_pyerr)?;
-            unimplemented!()
+    fn get_network_namespace(&self, ns_uuid: String) -> PyResult<PyObject> {
+        let ns_uuid = parse_uuid(&ns_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t.get_network_namespace(ns_uuid).await.map_err(to_pyerr)?;
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
-    fn remove_network_namespace(&self, ns_uuid: String) -> PyResult<String> {
-        let ns_uuid = Uuid::parse_str(&ns_uuid)
-            .map_err(|err| PyErr::new::<crate::FError, _>(err.to_string()))?;
-        task::block_on(async {
-            self.t
+    fn remove_network_namespace(&self, ns_uuid: String) -> PyResult<PyObject> {
+        let ns_uuid = parse_uuid(&ns_uuid)?;
+        let t = self.local()?;
+        self.z.call(None, async {
+            let r = t
                 .remove_network_namespace(ns_uuid)
                 .await
                 .map_err(to_pyerr)?;
-            unimplemented!()
+            Python::with_gil(|py| crate::to_dict(py, &r))
         })
     }
 
     fn add_network_namespace(&self, ns_info: String) -> PyResult<String> {
-        unimplemented!()
+        Err(crate::unimplemented_err("Local.add_network_namespace"))
         // task::block_on(async {
         //     self.t.add_network_namespace(ns_info).await.map_err(to_pyerr)?;
         //     unimplemented!()
@@ -725,6 +711,12 @@ impl Local {
     }
 }
 
+impl Local {
+    fn local(&self) -> PyResult<rustLocal> {
+        let zenoh = self.z.handles()?.zenoh;
+        Ok(rustLocal::new(zenoh, self.node_uuid))
+    }
+}
 
 #[pyproto]
 impl PyObjectProtocol for Local {
@@ -739,4 +731,4 @@ impl PyObjectProtocol for Local {
     fn __format__(&self, _format_spec: &str) -> PyResult<String> {
         self.__str__()
     }
-}
\ No newline at end of file
+}